@@ -0,0 +1,29 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use hashcom_rs::{HashCommitmentScheme, SHA256Commitment, SHA256CommitmentEngine};
+
+const COMMITMENTS: u64 = 1_000;
+
+fn bench_commit(c: &mut Criterion) {
+    let r: [u8; 16] = *b"2424242424242424";
+    let engine = SHA256CommitmentEngine::new();
+
+    let mut group = c.benchmark_group("sha256_commit");
+    group.bench_function("commitment_engine", |b| {
+        b.iter(|| {
+            for s in 0..COMMITMENTS {
+                black_box(engine.commit(&s, &r).unwrap());
+            }
+        })
+    });
+    group.bench_function("digest_commitment", |b| {
+        b.iter(|| {
+            for s in 0..COMMITMENTS {
+                black_box(SHA256Commitment::new(&s, &r).commit().unwrap());
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_commit);
+criterion_main!(benches);
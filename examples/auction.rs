@@ -0,0 +1,62 @@
+//! A sealed-bid auction, where each bidder commits to a bid before any bid is revealed.
+//!
+//! Each commitment is bound to the index of its bidder, so that a bidder can not copy the
+//! commitment of another one and open it once the other bid has been revealed. Run it with:
+//! ```sh
+//! cargo run --example auction --features rand
+//! ```
+
+#[path = "auction/winner.rs"]
+mod winner;
+
+use hashcom_rs::{Commitment, Opening, Result, SHA256Commitment};
+use winner::find_winner;
+
+/// A bidder, who keeps its opening secret until the reveal phase.
+struct Bidder {
+    name: &'static str,
+    opening: Opening<u64>,
+}
+
+impl Bidder {
+    /// Draws a random number to hide the bid.
+    fn new(name: &'static str, bid: u64) -> Bidder {
+        let opening = SHA256Commitment::with_secure_random(&bid).open();
+
+        Bidder { name, opening }
+    }
+
+    /// Commits to the bid during the commit phase, bound to the index of the bidder.
+    fn commit(&self, index: u64) -> Result<Commitment> {
+        SHA256Commitment::commit_indexed(index, self.opening.secret(), self.opening.randomness())
+    }
+}
+
+fn main() -> Result<()> {
+    let bidders = [
+        Bidder::new("alice", 4242),
+        Bidder::new("bob", 2424),
+        Bidder::new("carol", 4200),
+    ];
+
+    // Commit phase: the auctioneer collects one commitment per bidder.
+    let commitments = bidders
+        .iter()
+        .enumerate()
+        .map(|(index, bidder)| bidder.commit(index as u64))
+        .collect::<Result<Vec<_>>>()?;
+
+    // Reveal phase: once all the commitments are collected, the bidders reveal their openings.
+    let openings: Vec<Opening<u64>> = bidders.iter().map(|b| b.opening.clone()).collect();
+
+    match find_winner(&commitments, &openings)? {
+        Some(index) => println!(
+            "{} wins with a bid of {}",
+            bidders[index].name,
+            openings[index].secret()
+        ),
+        None => println!("no valid bid"),
+    }
+
+    Ok(())
+}
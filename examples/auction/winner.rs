@@ -0,0 +1,31 @@
+//! The rules deciding the winner of the sealed-bid auction, shared with the integration tests.
+
+use hashcom_rs::{Commitment, Opening, Result, SHA256Commitment};
+
+/// Returns the index of the highest bid whose opening matches the commitment of its bidder.
+///
+/// Bidders whose opening does not match are disqualified. Ties are won by the earliest bidder.
+pub fn find_winner(commitments: &[Commitment], openings: &[Opening<u64>]) -> Result<Option<usize>> {
+    let mut winner: Option<(usize, u64)> = None;
+
+    for (index, (com, opening)) in commitments.iter().zip(openings).enumerate() {
+        let valid = SHA256Commitment::verify_indexed(
+            com,
+            index as u64,
+            opening.secret(),
+            opening.randomness(),
+        )?;
+        let bid = *opening.secret();
+
+        let is_highest = match winner {
+            Some((_, best)) => bid > best,
+            None => true,
+        };
+
+        if valid && is_highest {
+            winner = Some((index, bid));
+        }
+    }
+
+    Ok(winner.map(|(index, _)| index))
+}
@@ -0,0 +1,13 @@
+#![no_main]
+
+use hashcom_rs::{raw_commit, raw_verify};
+use libfuzzer_sys::fuzz_target;
+
+// The input is split into a commitment, a secret and a random number, so that arbitrary and
+// malformed commitments are verified as well as freshly forged ones.
+fuzz_target!(|data: (Vec<u8>, Vec<u8>, Vec<u8>)| {
+    let (com, s, r) = data;
+
+    assert!(raw_verify(&raw_commit(&s, &r), &s, &r));
+    let _ = raw_verify(&com, &s, &r);
+});
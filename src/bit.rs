@@ -0,0 +1,95 @@
+use crate::{
+    check_commitment_len, check_randomness_len, framing_hasher, length_prefix, Commitment, Result,
+    DEFAULT_MIN_RANDOMNESS_LEN,
+};
+use core::marker::PhantomData;
+use sha2::digest::typenum::Unsigned;
+use sha2::{Digest, Sha256};
+use subtle::ConstantTimeEq;
+
+/// The domain-separation tag of the bit commitments, so that they can never collide with the
+/// commitments of the other schemes.
+const BIT_DOMAIN: &[u8] = b"hashcom-rs/bit";
+
+/// An implementation of the Hash Commitment Scheme specialized for single-bit secrets.
+///
+/// The bit is not encoded with a serializer, but hashed as a single byte after a fixed
+/// domain-separation tag, followed by the length-prefixed random number. The preimages of a
+/// 0-commitment and a 1-commitment therefore have the same size and format.
+pub struct BitCommitment<D: Digest> {
+    _digest: PhantomData<D>,
+}
+
+/// An implementation of the Hash Commitment Scheme for single bits using the SHA256 hash
+/// function.
+pub type SHA256BitCommitment = BitCommitment<Sha256>;
+
+impl<D: Digest> BitCommitment<D> {
+    /// The length in bytes of the commitments produced by the scheme.
+    pub const COMMITMENT_LEN: usize = D::OutputSize::USIZE;
+
+    /// Creates the commitment to the bit using the random number r.
+    ///
+    /// Returns a [`HashCommitmentError::InsufficientRandomness`](crate::HashCommitmentError)
+    /// if the random number is too short to hide the bit.
+    pub fn commit_bit(bit: bool, r: &[u8]) -> Result<Commitment> {
+        check_randomness_len(r, DEFAULT_MIN_RANDOMNESS_LEN)?;
+
+        Ok(Self::forge_commitment(bit, r))
+    }
+
+    /// Creates the expected commitment using the prover's bit and random number, then compares
+    /// it with the prover's one in constant time.
+    ///
+    /// A commitment whose length differs from `COMMITMENT_LEN` is rejected with a
+    /// [`HashCommitmentError::LengthMismatch`](crate::HashCommitmentError) before any hashing.
+    pub fn verify_bit(com: &Commitment, bit: bool, r: &[u8]) -> Result<bool> {
+        check_commitment_len(com, Self::COMMITMENT_LEN)?;
+
+        let expected_commitment = Self::forge_commitment(bit, r);
+
+        Ok(expected_commitment.as_bytes().ct_eq(com.as_bytes()).into())
+    }
+
+    /// Forges a commitment by hashing the length-prefixed domain-separation tag, the bit as a
+    /// single byte, then the length-prefixed random number r.
+    fn forge_commitment(bit: bool, r: &[u8]) -> Commitment {
+        let hash = framing_hasher::<D>(Some(BIT_DOMAIN), None)
+            .chain_update([u8::from(bit)])
+            .chain_update(length_prefix(r))
+            .chain_update(r)
+            .finalize();
+
+        Commitment::from(hash.as_slice())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SHA256BitCommitment;
+    use crate::{HashCommitmentScheme, SHA256Commitment};
+
+    #[test]
+    fn it_commits_to_a_bit() {
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let zero_commit = SHA256BitCommitment::commit_bit(false, &r).unwrap();
+        let one_commit = SHA256BitCommitment::commit_bit(true, &r).unwrap();
+
+        assert_eq!(zero_commit.len(), one_commit.len());
+        assert!(SHA256BitCommitment::verify_bit(&zero_commit, false, &r).unwrap());
+        assert!(!SHA256BitCommitment::verify_bit(&zero_commit, true, &r).unwrap());
+        assert!(SHA256BitCommitment::verify_bit(&one_commit, true, &r).unwrap());
+        assert!(!SHA256BitCommitment::verify_bit(&one_commit, false, &r).unwrap())
+    }
+
+    #[test]
+    fn it_does_not_collide_with_a_serialized_bool() {
+        let r: [u8; 16] = *b"2424242424242424";
+
+        assert_ne!(
+            SHA256BitCommitment::commit_bit(true, &r).unwrap(),
+            SHA256Commitment::new(&true, &r).commit().unwrap()
+        )
+    }
+}
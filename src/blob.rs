@@ -0,0 +1,239 @@
+use crate::{check_commitment_len, framing_hasher, length_prefix, HashCommitmentError, Result};
+use alloc::vec::Vec;
+use sha2::digest::typenum::Unsigned;
+use sha2::digest::Output;
+use sha2::{Digest, Sha256, Sha512};
+use subtle::ConstantTimeEq;
+
+#[cfg(feature = "blake2")]
+use blake2::Blake2b512;
+#[cfg(feature = "keccak")]
+use sha3::Keccak256;
+#[cfg(feature = "sha3")]
+use sha3::Sha3_256;
+
+/// A hash function identified by a stable name, used as the scheme id of the opening blobs.
+///
+/// The name is written in the blob when committing, and checked against the hash function used
+/// by [`verify_blob`], so that an opening can never be verified under another scheme than the one
+/// it was created for.
+pub trait NamedDigest: Digest {
+    /// The name of the hash function.
+    const NAME: &'static str;
+}
+
+impl NamedDigest for Sha256 {
+    const NAME: &'static str = "sha256";
+}
+
+impl NamedDigest for Sha512 {
+    const NAME: &'static str = "sha512";
+}
+
+#[cfg(feature = "sha3")]
+impl NamedDigest for Sha3_256 {
+    const NAME: &'static str = "sha3-256";
+}
+
+#[cfg(feature = "keccak")]
+impl NamedDigest for Keccak256 {
+    const NAME: &'static str = "keccak256";
+}
+
+#[cfg(feature = "blake2")]
+impl NamedDigest for Blake2b512 {
+    const NAME: &'static str = "blake2b512";
+}
+
+/// Writes a self-describing opening blob.
+///
+/// The blob holds, in order, the scheme id, the encoded secret and the random number, each
+/// prefixed with its length. They are followed by the optional domain and associated data, each
+/// preceded by a presence byte.
+pub(crate) fn encode_opening(
+    scheme: &str,
+    encoded_s: &[u8],
+    r: &[u8],
+    domain: Option<&[u8]>,
+    aad: Option<&[u8]>,
+) -> Vec<u8> {
+    let mut blob = Vec::new();
+    for field in [scheme.as_bytes(), encoded_s, r] {
+        blob.extend_from_slice(&length_prefix(field));
+        blob.extend_from_slice(field);
+    }
+    for field in [domain, aad] {
+        match field {
+            Some(field) => {
+                blob.push(1);
+                blob.extend_from_slice(&length_prefix(field));
+                blob.extend_from_slice(field);
+            }
+            None => blob.push(0),
+        }
+    }
+
+    blob
+}
+
+/// Verifies a commitment using the opening blob created along with it by `commit_with_opening`.
+///
+/// The commitment is forged again from the encoded secret and the random number stored in the
+/// blob, under the domain and associated data expected by the verifier, then compared with the
+/// prover's one in constant time. The secret does not need to be decoded, hence the absence of a
+/// type for it.
+///
+/// The blob is supplied by the prover, so the domain and associated data it stores are never
+/// trusted: a blob created under another domain or associated data than the expected ones is
+/// rejected with a [`HashCommitmentError::ContextMismatch`], so that a commitment forged for one
+/// protocol can not be verified in another one.
+///
+/// Returns a [`HashCommitmentError::SchemeMismatch`] if the blob was created using another hash
+/// function than D, and a [`HashCommitmentError::InvalidBlob`] if it is malformed.
+pub fn verify_blob<D: NamedDigest>(
+    com: &(impl AsRef<[u8]> + ?Sized),
+    blob: &[u8],
+    domain: Option<&[u8]>,
+    aad: Option<&[u8]>,
+) -> Result<bool> {
+    let mut reader = BlobReader(blob);
+    let scheme = reader.field()?;
+    let encoded_s = reader.field()?;
+    let r = reader.field()?;
+    let blob_domain = reader.optional_field()?;
+    let blob_aad = reader.optional_field()?;
+    if !reader.0.is_empty() {
+        return Err(HashCommitmentError::InvalidBlob);
+    }
+
+    if scheme != D::NAME.as_bytes() {
+        return Err(HashCommitmentError::SchemeMismatch);
+    }
+    if blob_domain != domain || blob_aad != aad {
+        return Err(HashCommitmentError::ContextMismatch);
+    }
+    check_commitment_len(com, D::OutputSize::USIZE)?;
+
+    let expected_commitment = forge_encoded::<D>(encoded_s, r, domain, aad);
+
+    Ok(expected_commitment.ct_eq(com.as_ref()).into())
+}
+
+/// Forges the commitment to an already encoded secret, framed the same way as the preimage of a
+/// [`DigestCommitment`](crate::DigestCommitment) with the given domain and associated data.
+pub(crate) fn forge_encoded<D: Digest>(
+    encoded_s: &[u8],
+    r: &[u8],
+    domain: Option<&[u8]>,
+    aad: Option<&[u8]>,
+) -> Output<D> {
+    framing_hasher::<D>(domain, aad)
+        .chain_update(length_prefix(encoded_s))
+        .chain_update(encoded_s)
+        .chain_update(length_prefix(r))
+        .chain_update(r)
+        .finalize()
+}
+
+/// A cursor over the fields of an opening blob.
+struct BlobReader<'a>(&'a [u8]);
+
+impl<'a> BlobReader<'a> {
+    /// Reads the given number of bytes.
+    fn take(&mut self, len: usize) -> Result<&'a [u8]> {
+        if self.0.len() < len {
+            return Err(HashCommitmentError::InvalidBlob);
+        }
+        let (head, tail) = self.0.split_at(len);
+        self.0 = tail;
+
+        Ok(head)
+    }
+
+    /// Reads a length-prefixed field.
+    fn field(&mut self) -> Result<&'a [u8]> {
+        let mut prefix = [0u8; 8];
+        prefix.copy_from_slice(self.take(8)?);
+        let len = usize::try_from(u64::from_be_bytes(prefix))
+            .map_err(|_| HashCommitmentError::InvalidBlob)?;
+
+        self.take(len)
+    }
+
+    /// Reads a length-prefixed field preceded by its presence byte.
+    fn optional_field(&mut self) -> Result<Option<&'a [u8]>> {
+        match self.take(1)? {
+            [0] => Ok(None),
+            [1] => self.field().map(Some),
+            _ => Err(HashCommitmentError::InvalidBlob),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::verify_blob;
+    use crate::{HashCommitmentError, HashCommitmentScheme, SHA256Commitment};
+    use sha2::{Sha256, Sha512};
+
+    #[test]
+    fn it_round_trips_through_an_opening_blob() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let party = SHA256Commitment::with_domain(&s, &r, b"protocol");
+        let (commit, blob) = party.commit_with_opening().unwrap();
+
+        assert_eq!(commit, party.commit().unwrap());
+        assert!(verify_blob::<Sha256>(&commit, &blob, Some(b"protocol"), None).unwrap());
+        assert!(verify_blob::<Sha256>(commit.as_bytes(), &blob, Some(b"protocol"), None).unwrap());
+
+        let other_commit = SHA256Commitment::new(&s, &r).commit().unwrap();
+
+        assert!(!verify_blob::<Sha256>(&other_commit, &blob, Some(b"protocol"), None).unwrap())
+    }
+
+    #[test]
+    fn it_rejects_a_blob_of_another_domain() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let (commit, blob) = SHA256Commitment::with_domain(&s, &r, b"protocol-a")
+            .commit_with_opening()
+            .unwrap();
+        let (aad_commit, aad_blob) = SHA256Commitment::with_associated_data(&s, &r, b"session-a")
+            .commit_with_opening()
+            .unwrap();
+
+        assert!(matches!(
+            verify_blob::<Sha256>(&commit, &blob, Some(b"protocol-b"), None),
+            Err(HashCommitmentError::ContextMismatch)
+        ));
+        assert!(matches!(
+            verify_blob::<Sha256>(&commit, &blob, None, None),
+            Err(HashCommitmentError::ContextMismatch)
+        ));
+        assert!(matches!(
+            verify_blob::<Sha256>(&aad_commit, &aad_blob, None, Some(b"session-b")),
+            Err(HashCommitmentError::ContextMismatch)
+        ));
+        assert!(verify_blob::<Sha256>(&aad_commit, &aad_blob, None, Some(b"session-a")).unwrap())
+    }
+
+    #[test]
+    fn it_rejects_a_blob_of_another_scheme() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let (commit, blob) = SHA256Commitment::new(&s, &r).commit_with_opening().unwrap();
+
+        assert!(matches!(
+            verify_blob::<Sha512>(&commit, &blob, None, None),
+            Err(HashCommitmentError::SchemeMismatch)
+        ));
+        assert!(matches!(
+            verify_blob::<Sha256>(&commit, &blob[..blob.len() - 1], None, None),
+            Err(HashCommitmentError::InvalidBlob)
+        ))
+    }
+}
@@ -0,0 +1,131 @@
+use crate::serializer::{BincodeSerializer, Serializer};
+use crate::{DigestCommitment, DEFAULT_MIN_RANDOMNESS_LEN};
+use alloc::borrow::Cow;
+use core::marker::PhantomData;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+/// A builder used to configure a [`DigestCommitment`] before creating it.
+///
+/// It gathers in one entry point the options which can be set on a party: the domain-separation
+/// tag, the serializer used to encode the secret, and the minimum length of the random number.
+/// Options which are not set keep the same defaults as [`DigestCommitment::new`].
+pub struct CommitmentBuilder<'a, S: Serializer = BincodeSerializer> {
+    domain: Option<&'a [u8]>,
+    min_randomness: usize,
+    _serializer: PhantomData<S>,
+}
+
+impl<'a> CommitmentBuilder<'a> {
+    /// Creates a new builder using the default options.
+    pub fn new() -> CommitmentBuilder<'a> {
+        CommitmentBuilder {
+            domain: None,
+            min_randomness: DEFAULT_MIN_RANDOMNESS_LEN,
+            _serializer: PhantomData,
+        }
+    }
+}
+
+impl<'a> Default for CommitmentBuilder<'a> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, S: Serializer> CommitmentBuilder<'a, S> {
+    /// Sets the domain-separation tag bound into the commitments.
+    pub fn domain(mut self, domain: &'a [u8]) -> Self {
+        self.domain = Some(domain);
+        self
+    }
+
+    /// Sets the serializer used to encode the secret.
+    pub fn serializer<S2: Serializer>(self) -> CommitmentBuilder<'a, S2> {
+        CommitmentBuilder {
+            domain: self.domain,
+            min_randomness: self.min_randomness,
+            _serializer: PhantomData,
+        }
+    }
+
+    /// Sets the minimum length in bytes of the random number accepted at commit time.
+    pub fn min_randomness(mut self, min_randomness: usize) -> Self {
+        self.min_randomness = min_randomness;
+        self
+    }
+
+    /// Creates a configured party for the Commitment Scheme using the hash function D.
+    pub fn build<T: Serialize, D: Digest>(
+        self,
+        s: &'a T,
+        r: &'a [u8],
+    ) -> DigestCommitment<'a, T, D, S> {
+        DigestCommitment {
+            s,
+            r: Cow::Borrowed(r),
+            domain: self.domain,
+            aad: None,
+            min_randomness: self.min_randomness,
+            _digest: PhantomData,
+            _serializer: PhantomData,
+        }
+    }
+
+    /// Creates a configured party for the Commitment Scheme using the SHA256 hash function.
+    pub fn build_sha256<T: Serialize>(
+        self,
+        s: &'a T,
+        r: &'a [u8],
+    ) -> DigestCommitment<'a, T, Sha256, S> {
+        self.build(s, r)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CommitmentBuilder;
+    use crate::{HashCommitmentError, HashCommitmentScheme, SHA256Commitment};
+
+    #[test]
+    fn it_builds_a_configured_commitment() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 8] = *b"24242424";
+
+        let party = CommitmentBuilder::new()
+            .domain(b"protocol-a")
+            .min_randomness(8)
+            .build_sha256(&s, &r);
+        let commit = party.commit().unwrap();
+
+        let expected_commit = SHA256Commitment::with_domain(&s, &r, b"protocol-a")
+            .with_min_randomness(8)
+            .commit()
+            .unwrap();
+
+        assert_eq!(commit, expected_commit);
+        assert!(party.verify(&commit, &s, &r).unwrap());
+        assert!(matches!(
+            CommitmentBuilder::new().build_sha256(&s, &r).commit(),
+            Err(HashCommitmentError::InsufficientRandomness { got: 8, min: 16 })
+        ))
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn it_builds_a_commitment_with_a_custom_serializer() {
+        use crate::serializer::JsonSerializer;
+        use crate::DigestCommitment;
+        use sha2::Sha256;
+
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let party = CommitmentBuilder::new()
+            .serializer::<JsonSerializer>()
+            .build_sha256(&s, &r);
+        let expected_party = DigestCommitment::<_, Sha256, JsonSerializer>::new(&s, &r);
+
+        assert_eq!(party.commit().unwrap(), expected_party.commit().unwrap())
+    }
+}
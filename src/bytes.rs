@@ -0,0 +1,419 @@
+use crate::{
+    check_commitment_len, check_randomness_len, framing_hasher, length_prefix, Commitment, Result,
+    DEFAULT_MIN_RANDOMNESS_LEN,
+};
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use sha2::digest::typenum::Unsigned;
+use sha2::{Digest, Sha256};
+use subtle::ConstantTimeEq;
+
+#[cfg(feature = "std")]
+use sha2::digest::Output;
+#[cfg(feature = "std")]
+use std::io::{ErrorKind, Read};
+
+/// The domain-separation tag hashed first in the preimage of the tagged commitments.
+const TAGGED_DOMAIN: &[u8] = b"hashcom-rs/tagged";
+
+/// The size of the chunks read from a reader when streaming a secret through the hash function.
+#[cfg(feature = "std")]
+const READ_CHUNK_LEN: usize = 8 * 1024;
+
+/// Forges the SHA256 commitment to the raw secret s using the random number r.
+///
+/// This is the same commitment as the one forged by a [`SHA256BytesCommitment`], except that
+/// the length of the random number is not checked, so any input is accepted. This makes it
+/// suited to fuzzing and to bindings which operate on raw bytes.
+pub fn raw_commit(s: &[u8], r: &[u8]) -> Vec<u8> {
+    SHA256BytesCommitment::new(s, r)
+        .forge_commitment(s, r)
+        .into()
+}
+
+/// Verifies that the raw secret s and the random number r open the SHA256 commitment com.
+///
+/// This function never panics: a malformed commitment simply does not hold.
+pub fn raw_verify(com: &[u8], s: &[u8], r: &[u8]) -> bool {
+    SHA256BytesCommitment::new(s, r)
+        .verify(&Commitment::from(com), s, r)
+        .unwrap_or(false)
+}
+
+/// An implementation of the Hash Commitment Scheme for secrets which are already byte arrays.
+///
+/// Unlike [`DigestCommitment`](crate::DigestCommitment), the secret is not encoded with
+/// bincode and neither field is length-prefixed: the commitment is the hash of the raw
+/// concatenation of the secret and the random number. This makes the commitments easy to
+/// reproduce with other implementations hashing raw preimages.
+///
+/// As the preimage is not framed, the binding property only holds if the verifier knows the
+/// length of the random number in advance. Parties should agree on a fixed length for it.
+pub struct BytesCommitment<'a, D: Digest> {
+    s: &'a [u8],
+    r: &'a [u8],
+    min_randomness: usize,
+    _digest: PhantomData<D>,
+}
+
+/// An implementation of the Hash Commitment Scheme for byte arrays using the SHA256 hash
+/// function.
+pub type SHA256BytesCommitment<'a> = BytesCommitment<'a, Sha256>;
+
+impl<'a, D: Digest> BytesCommitment<'a, D> {
+    /// Creates a new party for the Commitment Scheme using its secret and random number.
+    pub fn new(s: &'a [u8], r: &'a [u8]) -> BytesCommitment<'a, D> {
+        BytesCommitment {
+            s,
+            r,
+            min_randomness: DEFAULT_MIN_RANDOMNESS_LEN,
+            _digest: PhantomData,
+        }
+    }
+
+    /// Sets the minimum length in bytes of the random number accepted at commit time, which
+    /// defaults to [`DEFAULT_MIN_RANDOMNESS_LEN`](crate::DEFAULT_MIN_RANDOMNESS_LEN).
+    pub fn with_min_randomness(mut self, min_randomness: usize) -> Self {
+        self.min_randomness = min_randomness;
+        self
+    }
+
+    /// Creates the commitment used during the commit phase.
+    ///
+    /// Returns a [`HashCommitmentError::InsufficientRandomness`](crate::HashCommitmentError)
+    /// if the random number is too short to hide the secret.
+    pub fn commit(&self) -> Result<Commitment> {
+        check_randomness_len(self.r, self.min_randomness)?;
+
+        Ok(self.forge_commitment(self.s, self.r))
+    }
+
+    /// Creates the expected commitment using the prover's secret and random number.
+    /// Then, compares the expected commitment with the prover's one in constant time to verify
+    /// if the commitment holds.
+    ///
+    /// A commitment whose length differs from the output size of the hash function is rejected
+    /// with a [`HashCommitmentError::LengthMismatch`](crate::HashCommitmentError) before any
+    /// hashing.
+    pub fn verify<C: AsRef<[u8]> + ?Sized>(&self, com: &C, s: &[u8], r: &[u8]) -> Result<bool> {
+        check_commitment_len(com, D::OutputSize::USIZE)?;
+
+        let expected_commitment = self.forge_commitment(s, r);
+
+        Ok(expected_commitment.as_bytes().ct_eq(com.as_ref()).into())
+    }
+
+    /// Forges a commitment to the secret read from the given reader, using the random number r.
+    ///
+    /// The secret is streamed through the hash function in chunks, so it never has to be held
+    /// in memory as a whole. The commitment is the same as the one forged by a party created
+    /// with the full secret.
+    ///
+    /// The random number must be at least
+    /// [`DEFAULT_MIN_RANDOMNESS_LEN`](crate::DEFAULT_MIN_RANDOMNESS_LEN) bytes long.
+    ///
+    /// This method is only available when the `std` feature is enabled.
+    #[cfg(feature = "std")]
+    pub fn commit_reader<R: Read>(reader: R, r: &[u8]) -> Result<Commitment> {
+        check_randomness_len(r, DEFAULT_MIN_RANDOMNESS_LEN)?;
+
+        let hash = Self::digest_reader(reader, r)?;
+
+        Ok(Commitment::from(hash.to_vec()))
+    }
+
+    /// Verifies that the secret read from the given reader and the random number r open the
+    /// commitment com.
+    ///
+    /// The secret is streamed through the hash function in chunks, as done by `commit_reader`,
+    /// and the expected commitment is compared with the prover's one in constant time.
+    ///
+    /// A commitment whose length differs from the output size of the hash function is rejected
+    /// with a [`HashCommitmentError::LengthMismatch`](crate::HashCommitmentError) before the
+    /// reader is consumed.
+    ///
+    /// This method is only available when the `std` feature is enabled.
+    #[cfg(feature = "std")]
+    pub fn verify_reader<R: Read>(com: &[u8], reader: R, r: &[u8]) -> Result<bool> {
+        check_commitment_len(com, D::OutputSize::USIZE)?;
+
+        let expected_commitment = Self::digest_reader(reader, r)?;
+
+        Ok(expected_commitment.ct_eq(com).into())
+    }
+
+    /// Hashes the secret read from the given reader in chunks, followed by the random number r.
+    #[cfg(feature = "std")]
+    fn digest_reader<R: Read>(mut reader: R, r: &[u8]) -> Result<Output<D>> {
+        let mut hasher = D::new();
+        let mut chunk = [0u8; READ_CHUNK_LEN];
+
+        loop {
+            match reader.read(&mut chunk) {
+                Ok(0) => break,
+                Ok(n) => hasher.update(&chunk[..n]),
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e.into()),
+            }
+        }
+
+        Ok(hasher.chain_update(r).finalize())
+    }
+
+    /// Forges a commitment to the secret made of the concatenation of the given chunks, using
+    /// the random number r.
+    ///
+    /// Each chunk is fed to the hash function in order, so the secret never has to be
+    /// concatenated in memory. As the preimage is not framed, the chunk boundaries do not change
+    /// the commitment, which is the same as the one forged by a party created with the
+    /// concatenated secret.
+    ///
+    /// The random number must be at least
+    /// [`DEFAULT_MIN_RANDOMNESS_LEN`](crate::DEFAULT_MIN_RANDOMNESS_LEN) bytes long.
+    pub fn commit_chunks<I>(chunks: I, r: &[u8]) -> Result<Commitment>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<[u8]>,
+    {
+        check_randomness_len(r, DEFAULT_MIN_RANDOMNESS_LEN)?;
+
+        let hash = chunks
+            .into_iter()
+            .fold(D::new(), |hasher, chunk| hasher.chain_update(chunk))
+            .chain_update(r)
+            .finalize();
+
+        Ok(Commitment::from(hash.to_vec()))
+    }
+
+    /// Forges a commitment to a struct-like secret made of labelled fields, using the random
+    /// number r.
+    ///
+    /// Unlike the raw commitments of this scheme, the preimage is framed: a fixed
+    /// domain-separation tag is hashed first, then each field is hashed as its length-prefixed
+    /// tag followed by its length-prefixed value, and finally the random number is hashed with
+    /// its length prefix. Binding a tag to each value means that swapping two fields of the same
+    /// type changes the commitment, and the fixed domain means that a single field can not be
+    /// passed off as a domain-separated [`DigestCommitment`](crate::DigestCommitment).
+    ///
+    /// The random number must be at least
+    /// [`DEFAULT_MIN_RANDOMNESS_LEN`](crate::DEFAULT_MIN_RANDOMNESS_LEN) bytes long.
+    pub fn commit_tagged(fields: &[(&str, &[u8])], r: &[u8]) -> Result<Commitment> {
+        check_randomness_len(r, DEFAULT_MIN_RANDOMNESS_LEN)?;
+
+        Ok(Self::forge_tagged_commitment(fields, r))
+    }
+
+    /// Verifies that the labelled fields and the random number r open the commitment com,
+    /// forged by `commit_tagged`, comparing the commitments in constant time.
+    ///
+    /// A commitment whose length differs from the output size of the hash function is rejected
+    /// with a [`HashCommitmentError::LengthMismatch`](crate::HashCommitmentError) before any
+    /// hashing.
+    pub fn verify_tagged(com: &Commitment, fields: &[(&str, &[u8])], r: &[u8]) -> Result<bool> {
+        check_commitment_len(com, D::OutputSize::USIZE)?;
+
+        let expected_commitment = Self::forge_tagged_commitment(fields, r);
+
+        Ok(expected_commitment.as_bytes().ct_eq(com.as_bytes()).into())
+    }
+
+    /// Forges a commitment by hashing the length-prefixed domain of the tagged commitments, each
+    /// length-prefixed tag and value, then the length-prefixed random number r.
+    fn forge_tagged_commitment(fields: &[(&str, &[u8])], r: &[u8]) -> Commitment {
+        let hash = fields
+            .iter()
+            .fold(
+                framing_hasher::<D>(Some(TAGGED_DOMAIN), None),
+                |hasher, (tag, value)| {
+                    hasher
+                        .chain_update(length_prefix(tag.as_bytes()))
+                        .chain_update(tag)
+                        .chain_update(length_prefix(value))
+                        .chain_update(value)
+                },
+            )
+            .chain_update(length_prefix(r))
+            .chain_update(r)
+            .finalize();
+
+        Commitment::from(hash.to_vec())
+    }
+
+    /// Forges a commitment by hashing the secret s followed by the random number r.
+    fn forge_commitment(&self, s: &[u8], r: &[u8]) -> Commitment {
+        let hash = D::new().chain_update(s).chain_update(r).finalize();
+
+        Commitment::from(hash.to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{raw_commit, raw_verify, SHA256BytesCommitment};
+    use crate::{Commitment, HashCommitmentError, HashCommitmentScheme, SHA256Commitment};
+    use hex_literal::hex;
+    #[cfg(feature = "std")]
+    use std::io::Cursor;
+
+    /// The expected commitment is the SHA256 digest of "42422424242424242424", as computed by
+    /// `printf 42422424242424242424 | sha256sum`.
+    #[test]
+    fn it_commits_to_the_raw_preimage() {
+        let s = b"4242";
+        let r = b"2424242424242424";
+
+        let party = SHA256BytesCommitment::new(s, r);
+        let commit = party.commit();
+
+        assert!(commit.is_ok());
+        assert_eq!(
+            commit.unwrap().as_bytes(),
+            hex!("d46b6879d64a6ce1ef6b6decfd0895a2618e24418bab535512b33e33d32e79e1")
+        )
+    }
+
+    #[test]
+    fn it_verifies_valid_bytes_commitment() {
+        let s = b"4242";
+        let r = b"2424242424242424";
+
+        // Commit phase.
+        let party = SHA256BytesCommitment::new(s, r);
+        let commit = party.commit().unwrap();
+
+        // Verification phase.
+        assert!(party.verify(&commit, s, r).unwrap());
+        assert!(!party.verify(&commit, b"BDBD", r).unwrap());
+        assert!(!party.verify(&commit, s, b"BDBD").unwrap())
+    }
+
+    #[test]
+    fn it_commits_and_verifies_raw_bytes() {
+        let s = b"4242";
+        let r = b"2424242424242424";
+
+        let commit = raw_commit(s, r);
+
+        assert_eq!(
+            commit,
+            SHA256BytesCommitment::new(s, r)
+                .commit()
+                .unwrap()
+                .as_bytes()
+        );
+        assert!(raw_verify(&commit, s, r));
+        assert!(!raw_verify(&commit, b"BDBD", r));
+
+        // Empty and malformed inputs must not panic.
+        assert!(raw_verify(&raw_commit(&[], &[]), &[], &[]));
+        assert!(!raw_verify(&[], &[], &[]));
+        assert!(!raw_verify(&commit[..31], s, r))
+    }
+
+    #[test]
+    fn it_rejects_a_bytes_commitment_of_the_wrong_length() {
+        let s = b"4242";
+        let r = b"2424242424242424";
+
+        let party = SHA256BytesCommitment::new(s, r);
+        let verification = party.verify(&Commitment::from(vec![0u8; 16]), s, r);
+
+        assert!(matches!(
+            verification,
+            Err(HashCommitmentError::LengthMismatch {
+                expected: 32,
+                actual: 16
+            })
+        ))
+    }
+
+    #[test]
+    fn it_commits_to_chunks_like_their_concatenation() {
+        let s = b"4242BDBD4242";
+        let r = b"2424242424242424";
+
+        let commit = SHA256BytesCommitment::new(s, r).commit().unwrap();
+        let chunked_commit =
+            SHA256BytesCommitment::commit_chunks([&s[..3], &s[3..4], &[], &s[4..]], r).unwrap();
+        let vec_chunked_commit =
+            SHA256BytesCommitment::commit_chunks(s.chunks(5).map(<[u8]>::to_vec), r).unwrap();
+
+        assert_eq!(chunked_commit, commit);
+        assert_eq!(vec_chunked_commit, commit)
+    }
+
+    #[test]
+    fn it_binds_each_value_to_its_tag() {
+        let r = b"2424242424242424";
+        let fields: [(&str, &[u8]); 3] = [
+            ("amount", b"4242"),
+            ("recipient", b"BDBD"),
+            ("memo", b"2424"),
+        ];
+        let swapped_values: [(&str, &[u8]); 3] = [
+            ("amount", b"BDBD"),
+            ("recipient", b"4242"),
+            ("memo", b"2424"),
+        ];
+        let reordered_fields: [(&str, &[u8]); 3] = [fields[1], fields[0], fields[2]];
+
+        let commit = SHA256BytesCommitment::commit_tagged(&fields, r).unwrap();
+
+        assert!(SHA256BytesCommitment::verify_tagged(&commit, &fields, r).unwrap());
+        assert!(!SHA256BytesCommitment::verify_tagged(&commit, &swapped_values, r).unwrap());
+        assert_ne!(
+            SHA256BytesCommitment::commit_tagged(&reordered_fields, r).unwrap(),
+            commit
+        );
+        assert!(matches!(
+            SHA256BytesCommitment::commit_tagged(&fields, b"24"),
+            Err(HashCommitmentError::InsufficientRandomness { got: 2, min: 16 })
+        ))
+    }
+
+    #[test]
+    fn it_never_collides_with_a_domain_separated_commitment() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r = b"2424242424242424";
+        let encoded_s =
+            bincode::serde::encode_to_vec(s, crate::serializer::BINCODE_CONFIG).unwrap();
+
+        assert_ne!(
+            SHA256BytesCommitment::commit_tagged(&[("protocol", &encoded_s)], r).unwrap(),
+            SHA256Commitment::with_domain(&s, r, b"protocol")
+                .commit()
+                .unwrap()
+        )
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn it_commits_to_a_reader() {
+        let s: Vec<u8> = (0..100_000u32).map(|i| (i % 251) as u8).collect();
+        let r = b"2424242424242424";
+
+        let commit = SHA256BytesCommitment::commit_reader(Cursor::new(&s), r).unwrap();
+
+        assert_eq!(commit, SHA256BytesCommitment::new(&s, r).commit().unwrap())
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn it_verifies_a_reader() {
+        let mut s: Vec<u8> = (0..100_000u32).map(|i| (i % 251) as u8).collect();
+        let r = b"2424242424242424";
+
+        let commit = SHA256BytesCommitment::new(&s, r).commit().unwrap();
+
+        assert!(
+            SHA256BytesCommitment::verify_reader(commit.as_bytes(), Cursor::new(&s), r).unwrap()
+        );
+
+        s[50_000] ^= 1;
+
+        assert!(
+            !SHA256BytesCommitment::verify_reader(commit.as_bytes(), Cursor::new(&s), r).unwrap()
+        )
+    }
+}
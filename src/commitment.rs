@@ -0,0 +1,317 @@
+use crate::HashCommitmentError;
+use alloc::vec::Vec;
+#[cfg(feature = "base64")]
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::str::FromStr;
+use subtle::ConstantTimeEq;
+
+#[cfg(feature = "serde-derive")]
+use serde::{Deserialize, Serialize};
+
+/// A commitment forged by a party during the commit phase of a Hash Commitment Scheme.
+///
+/// This is a thin wrapper around the bytes produced by the hash function, which provides a
+/// type-level distinction between a commitment and arbitrary data.
+///
+/// Commitments are displayed as lowercase hex strings, and can be parsed back from a hex string
+/// using [`str::parse`]. When the `base64` feature is enabled, they can also be encoded to the
+/// shorter URL-safe Base64 alphabet, without padding.
+///
+/// Two commitments are compared in constant time, so that comparing a received commitment with
+/// an expected one does not leak how many of their leading bytes match. Commitments can also be
+/// hashed, to be used as keys of a `HashMap` or a `HashSet`.
+///
+/// When the `serde-derive` feature is enabled, commitments can be serialized as byte arrays.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde-derive", derive(Serialize, Deserialize))]
+pub struct Commitment(#[cfg_attr(feature = "serde-derive", serde(with = "serde_bytes"))] Vec<u8>);
+
+impl Commitment {
+    /// Returns the bytes of the commitment.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Returns the length of the commitment in bytes.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns true if the commitment does not hold any byte.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns a stable 64-bit fingerprint of the commitment, to be used as an index key.
+    ///
+    /// The fingerprint is made of the first 8 bytes of the commitment, read as a big-endian
+    /// u64, so it is the same on every platform. A commitment shorter than 8 bytes is padded
+    /// with zeros. The fingerprint is not meant to be cryptographically meaningful: two
+    /// commitments may share it.
+    pub fn fingerprint(&self) -> u64 {
+        let mut prefix = [0u8; 8];
+        let len = self.0.len().min(prefix.len());
+        prefix[..len].copy_from_slice(&self.0[..len]);
+
+        u64::from_be_bytes(prefix)
+    }
+
+    /// Checks that the commitment is well-formed, before any opening is received.
+    ///
+    /// Returns a [`HashCommitmentError::LengthMismatch`] if the commitment is not expected_len
+    /// bytes long, which should be the `COMMITMENT_LEN` of the scheme, and a
+    /// [`HashCommitmentError::DegenerateCommitment`] if all its bytes are zeros, which a hash
+    /// function will not output in practice. This is a cheap pre-filter: a well-formed
+    /// commitment may still not be opened by any secret.
+    pub fn validate(&self, expected_len: usize) -> Result<(), HashCommitmentError> {
+        if self.len() != expected_len {
+            return Err(HashCommitmentError::LengthMismatch {
+                expected: expected_len,
+                actual: self.len(),
+            });
+        }
+        if self.0.iter().all(|byte| *byte == 0) {
+            return Err(HashCommitmentError::DegenerateCommitment);
+        }
+
+        Ok(())
+    }
+
+    /// Encodes the commitment using the URL-safe Base64 alphabet, without padding.
+    ///
+    /// This method is only available when the `base64` feature is enabled.
+    #[cfg(feature = "base64")]
+    pub fn to_base64(&self) -> alloc::string::String {
+        URL_SAFE_NO_PAD.encode(&self.0)
+    }
+
+    /// Decodes a commitment encoded using the URL-safe Base64 alphabet, without padding.
+    ///
+    /// Returns a [`HashCommitmentError::InvalidBase64`] if the string is not valid Base64.
+    ///
+    /// This method is only available when the `base64` feature is enabled.
+    #[cfg(feature = "base64")]
+    pub fn from_base64(s: &str) -> Result<Self, HashCommitmentError> {
+        Ok(Commitment(URL_SAFE_NO_PAD.decode(s)?))
+    }
+}
+
+impl From<Vec<u8>> for Commitment {
+    fn from(bytes: Vec<u8>) -> Self {
+        Commitment(bytes)
+    }
+}
+
+impl From<&[u8]> for Commitment {
+    fn from(bytes: &[u8]) -> Self {
+        Commitment(bytes.to_vec())
+    }
+}
+
+impl From<Commitment> for Vec<u8> {
+    fn from(commitment: Commitment) -> Self {
+        commitment.0
+    }
+}
+
+/// Converts a commitment to a fixed-size array, such as the `[u8; 32]` expected for SHA256
+/// digests by many cryptography libraries.
+///
+/// Returns a [`HashCommitmentError::LengthMismatch`] if the commitment is not exactly N bytes
+/// long.
+impl<const N: usize> TryFrom<Commitment> for [u8; N] {
+    type Error = HashCommitmentError;
+
+    fn try_from(commitment: Commitment) -> Result<Self, Self::Error> {
+        let actual = commitment.len();
+
+        commitment
+            .0
+            .try_into()
+            .map_err(|_| HashCommitmentError::LengthMismatch {
+                expected: N,
+                actual,
+            })
+    }
+}
+
+impl AsRef<[u8]> for Commitment {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl PartialEq for Commitment {
+    /// Compares the bytes of both commitments in constant time.
+    fn eq(&self, other: &Self) -> bool {
+        self.0.ct_eq(&other.0).into()
+    }
+}
+
+impl Eq for Commitment {}
+
+impl Hash for Commitment {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+impl fmt::Display for Commitment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&base16ct::lower::encode_string(&self.0))
+    }
+}
+
+impl FromStr for Commitment {
+    type Err = HashCommitmentError;
+
+    /// Parses a hex-encoded commitment, regardless of the case of its digits.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Commitment(base16ct::mixed::decode_vec(s)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Commitment;
+    use crate::HashCommitmentError;
+
+    #[test]
+    fn it_fingerprints_a_commitment() {
+        use crate::{HashCommitmentScheme, SHA256Commitment};
+
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let commit = SHA256Commitment::new(&s, &r).commit().unwrap();
+
+        assert_eq!(commit.fingerprint(), 0x4385_e32a_44ae_244b);
+        assert_eq!(
+            Commitment::from(vec![1, 2]).fingerprint(),
+            0x0102_0000_0000_0000
+        )
+    }
+
+    #[test]
+    fn it_validates_a_commitment() {
+        use crate::{HashCommitmentScheme, SHA256Commitment};
+
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let commit = SHA256Commitment::new(&s, &r).commit().unwrap();
+        let expected_len = SHA256Commitment::<[u8; 4]>::COMMITMENT_LEN;
+
+        assert!(commit.validate(expected_len).is_ok());
+        assert!(matches!(
+            Commitment::from(&commit.as_bytes()[..16]).validate(expected_len),
+            Err(HashCommitmentError::LengthMismatch {
+                expected: 32,
+                actual: 16
+            })
+        ));
+        assert!(matches!(
+            Commitment::from(vec![0u8; 32]).validate(expected_len),
+            Err(HashCommitmentError::DegenerateCommitment)
+        ))
+    }
+
+    #[test]
+    fn it_wraps_bytes() {
+        let commit = Commitment::from(vec![42u8; 32]);
+
+        assert_eq!(commit.len(), 32);
+        assert!(!commit.is_empty());
+        assert_eq!(commit.as_bytes(), [42u8; 32])
+    }
+
+    #[test]
+    fn it_converts_from_and_to_byte_containers() {
+        let bytes = [42u8; 32];
+
+        let commit = Commitment::from(bytes.to_vec());
+        let sliced_commit = Commitment::from(&bytes[..]);
+
+        assert_eq!(commit, sliced_commit);
+        assert_eq!(commit.as_ref(), bytes);
+        assert_eq!(Vec::from(commit.clone()), bytes.to_vec());
+
+        let array: [u8; 32] = commit.try_into().unwrap();
+
+        assert_eq!(array, bytes)
+    }
+
+    #[test]
+    fn it_fails_to_convert_to_an_array_of_the_wrong_length() {
+        let commit = Commitment::from(vec![42u8; 16]);
+
+        let array: Result<[u8; 32], _> = commit.try_into();
+
+        assert!(matches!(
+            array,
+            Err(HashCommitmentError::LengthMismatch {
+                expected: 32,
+                actual: 16
+            })
+        ))
+    }
+
+    #[test]
+    fn it_compares_and_hashes_commitments() {
+        use std::collections::HashSet;
+
+        let commit = Commitment::from(vec![42u8; 32]);
+        let other_commit = Commitment::from(vec![24u8; 32]);
+
+        let commits: HashSet<Commitment> = [commit.clone(), other_commit.clone(), commit.clone()]
+            .into_iter()
+            .collect();
+
+        assert_eq!(commits.len(), 2);
+        assert!(commits.contains(&Commitment::from(vec![42u8; 32])));
+        assert!(commits.contains(&other_commit));
+        assert!(!commits.contains(&Commitment::from(vec![42u8; 16])));
+        assert_ne!(commit, Commitment::from(vec![42u8; 31]))
+    }
+
+    #[test]
+    fn it_round_trips_through_hex() {
+        let hex = "731072865296207ae76e3b5dd004cbb07bf594d68f00e49ac9d8714bee24e17d";
+
+        let commit: Commitment = hex.parse().unwrap();
+
+        assert_eq!(commit.len(), 32);
+        assert_eq!(commit.to_string(), hex);
+        assert_eq!(hex.to_uppercase().parse::<Commitment>().unwrap(), commit)
+    }
+
+    #[test]
+    fn it_fails_to_parse_invalid_hex() {
+        assert!(matches!(
+            "abc".parse::<Commitment>(),
+            Err(HashCommitmentError::InvalidHex(_))
+        ));
+        assert!(matches!(
+            "zz".parse::<Commitment>(),
+            Err(HashCommitmentError::InvalidHex(_))
+        ))
+    }
+
+    #[cfg(feature = "base64")]
+    #[test]
+    fn it_round_trips_through_base64() {
+        let hex = "731072865296207ae76e3b5dd004cbb07bf594d68f00e49ac9d8714bee24e17d";
+        let commit: Commitment = hex.parse().unwrap();
+
+        let encoded = commit.to_base64();
+
+        assert_eq!(encoded, "cxByhlKWIHrnbjtd0ATLsHv1lNaPAOSaydhxS-4k4X0");
+        assert_eq!(Commitment::from_base64(&encoded).unwrap(), commit);
+        assert!(matches!(
+            Commitment::from_base64("cxByhl+W"),
+            Err(HashCommitmentError::InvalidBase64(_))
+        ))
+    }
+}
@@ -0,0 +1,114 @@
+use crate::serializer::{BincodeSerializer, Serializer};
+use crate::{Commitment, DigestCommitment, HashCommitmentScheme, Result};
+use core::marker::PhantomData;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+/// A factory of commitments all bound to the same domain-separation tag.
+///
+/// The domain is configured once, then folded into every commitment forged or verified by the
+/// scheme, the same way as [`DigestCommitment::with_domain`]. This spares passing the domain to
+/// each call, and two schemes created with different domains never verify each other's
+/// commitments.
+///
+/// Under the default serializer, the commitments are the same as the ones of a
+/// [`CommitmentEngine`](crate::CommitmentEngine) created with the same domain. The engine
+/// is the faster choice in tight loops, as it feeds the domain to its hasher once, but it only
+/// encodes the secrets with bincode, while this scheme accepts any serializer S.
+pub struct DomainScheme<'a, D: Digest, S: Serializer = BincodeSerializer> {
+    domain: &'a [u8],
+    _digest: PhantomData<D>,
+    _serializer: PhantomData<S>,
+}
+
+/// A factory of commitments bound to a domain, using the SHA256 hash function.
+pub type SHA256DomainScheme<'a> = DomainScheme<'a, Sha256>;
+
+impl<'a, D: Digest, S: Serializer> DomainScheme<'a, D, S> {
+    /// Creates a new scheme binding the given domain-separation tag into every commitment.
+    pub fn new(domain: &'a [u8]) -> DomainScheme<'a, D, S> {
+        DomainScheme {
+            domain,
+            _digest: PhantomData,
+            _serializer: PhantomData,
+        }
+    }
+
+    /// Returns the domain-separation tag of the scheme.
+    pub fn domain(&self) -> &'a [u8] {
+        self.domain
+    }
+
+    /// Forges the commitment to the secret s and random number r under the scheme's domain.
+    ///
+    /// The random number must be at least
+    /// [`DEFAULT_MIN_RANDOMNESS_LEN`](crate::DEFAULT_MIN_RANDOMNESS_LEN) bytes long.
+    pub fn commit<T, R>(&self, s: &T, r: &R) -> Result<Commitment>
+    where
+        T: Serialize,
+        R: AsRef<[u8]> + ?Sized,
+    {
+        DigestCommitment::<T, D, S>::with_domain(s, r, self.domain).commit()
+    }
+
+    /// Verifies that the secret s and random number r open the commitment com under the
+    /// scheme's domain.
+    ///
+    /// The commitment and the random number can be borrowed from any byte container, as in
+    /// [`HashCommitmentScheme::verify`].
+    pub fn verify<T, C, R>(&self, com: &C, s: &T, r: &R) -> Result<bool>
+    where
+        T: Serialize,
+        C: AsRef<[u8]> + ?Sized,
+        R: AsRef<[u8]> + ?Sized,
+    {
+        DigestCommitment::<T, D, S>::with_domain(s, r, self.domain).verify(com, s, r)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SHA256DomainScheme;
+    use crate::{HashCommitmentScheme, SHA256Commitment, SHA256CommitmentEngine};
+
+    #[test]
+    fn it_binds_the_domain_into_every_commitment() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let scheme = SHA256DomainScheme::new(b"auction");
+        let commit = scheme.commit(&s, &r).unwrap();
+
+        assert_eq!(scheme.domain(), b"auction");
+        assert!(scheme.verify(&commit, &s, &r).unwrap());
+        assert!(scheme.verify(commit.as_bytes(), &s, &r[..]).unwrap());
+        assert_eq!(
+            commit,
+            SHA256CommitmentEngine::with_domain(b"auction")
+                .commit(&s, &r)
+                .unwrap()
+        );
+        assert_eq!(
+            commit,
+            SHA256Commitment::with_domain(&s, &r, b"auction")
+                .commit()
+                .unwrap()
+        )
+    }
+
+    #[test]
+    fn it_never_cross_verifies_between_domains() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let auction = SHA256DomainScheme::new(b"auction");
+        let lottery = SHA256DomainScheme::new(b"lottery");
+
+        let auction_commit = auction.commit(&s, &r).unwrap();
+        let lottery_commit = lottery.commit(&s, &r).unwrap();
+
+        assert_ne!(auction_commit, lottery_commit);
+        assert!(!auction.verify(&lottery_commit, &s, &r).unwrap());
+        assert!(!lottery.verify(&auction_commit, &s, &r).unwrap())
+    }
+}
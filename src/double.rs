@@ -0,0 +1,145 @@
+use crate::serializer::{BincodeSerializer, Serializer};
+use crate::{
+    check_randomness_len, length_prefix, Commitment, HashCommitmentScheme, Opening, Result,
+    DEFAULT_MIN_RANDOMNESS_LEN,
+};
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use serde::Serialize;
+use sha2::digest::typenum::Unsigned;
+use sha2::Digest;
+
+/// An implementation of the Hash Commitment Scheme combining two hash functions, to hedge
+/// against a break of either of them.
+///
+/// The commitment is `H1(preimage) || H2(preimage)`, where the preimage is framed the same way
+/// as the one of [`DigestCommitment`](crate::DigestCommitment). Forging a second opening
+/// therefore requires breaking both hash functions. The verification compares the whole
+/// commitment, hence both halves, in constant time.
+pub struct DoubleHashCommitment<'a, T: 'a + Serialize, D1: Digest, D2: Digest> {
+    s: &'a T,
+    r: &'a [u8],
+    min_randomness: usize,
+    _digests: PhantomData<(D1, D2)>,
+}
+
+impl<'a, T: 'a + Serialize, D1: Digest, D2: Digest> DoubleHashCommitment<'a, T, D1, D2> {
+    /// Creates a new party for the Commitment Scheme using its secret and random number.
+    pub fn new(s: &'a T, r: &'a [u8]) -> DoubleHashCommitment<'a, T, D1, D2> {
+        DoubleHashCommitment {
+            s,
+            r,
+            min_randomness: DEFAULT_MIN_RANDOMNESS_LEN,
+            _digests: PhantomData,
+        }
+    }
+
+    /// Sets the minimum length in bytes of the random number accepted at commit time, which
+    /// defaults to [`DEFAULT_MIN_RANDOMNESS_LEN`](crate::DEFAULT_MIN_RANDOMNESS_LEN).
+    pub fn with_min_randomness(mut self, min_randomness: usize) -> Self {
+        self.min_randomness = min_randomness;
+        self
+    }
+
+    /// Reveals the party's secret and random number, to be sent to the verifier during the open
+    /// phase.
+    pub fn open(&self) -> Opening<T>
+    where
+        T: Clone,
+    {
+        Opening::new(self.s.clone(), self.r.to_vec())
+    }
+
+    /// Forges a commitment given a secret s and a random number r, by concatenating the digests
+    /// of the same framed preimage under both hash functions.
+    fn forge_commitment(s: &T, r: &[u8]) -> Result<Vec<u8>> {
+        let binary_encoded_s = BincodeSerializer::serialize(s)?;
+
+        let mut commitment = Vec::with_capacity(Self::COMMITMENT_LEN);
+        commitment.extend_from_slice(&framed_digest::<D1>(&binary_encoded_s, r));
+        commitment.extend_from_slice(&framed_digest::<D2>(&binary_encoded_s, r));
+
+        Ok(commitment)
+    }
+}
+
+/// Hashes the length-prefixed encoded secret, followed by the length-prefixed random number.
+fn framed_digest<D: Digest>(binary_encoded_s: &[u8], r: &[u8]) -> sha2::digest::Output<D> {
+    D::new()
+        .chain_update(length_prefix(binary_encoded_s))
+        .chain_update(binary_encoded_s)
+        .chain_update(length_prefix(r))
+        .chain_update(r)
+        .finalize()
+}
+
+impl<'a, T: 'a + Serialize, D1: Digest, D2: Digest> HashCommitmentScheme<T>
+    for DoubleHashCommitment<'a, T, D1, D2>
+{
+    const COMMITMENT_LEN: usize = D1::OutputSize::USIZE + D2::OutputSize::USIZE;
+
+    /// Both digests are concatenated, so the commitment is built in a `Vec`.
+    type Output = Vec<u8>;
+
+    /// Creates the bytes of the commitment used during the commit phase.
+    ///
+    /// Returns a [`HashCommitmentError::InsufficientRandomness`](crate::HashCommitmentError)
+    /// if the random number is too short to hide the secret.
+    fn commit_output(&self) -> Result<Self::Output> {
+        check_randomness_len(self.r, self.min_randomness)?;
+
+        Self::forge_commitment(self.s, self.r)
+    }
+
+    fn recommit(&self, s: &T, r: &[u8]) -> Result<Commitment> {
+        Self::forge_commitment(s, r).map(Commitment::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DoubleHashCommitment;
+    use crate::{HashCommitmentScheme, SHA256Commitment, SHA512Commitment};
+    use sha2::{Sha256, Sha512};
+
+    #[test]
+    fn it_concatenates_both_digests() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let party = DoubleHashCommitment::<_, Sha256, Sha512>::new(&s, &r);
+        let commit = party.commit().unwrap();
+
+        assert_eq!(commit.len(), 96);
+        assert_eq!(
+            &commit.as_bytes()[..32],
+            SHA256Commitment::new(&s, &r).commit().unwrap().as_bytes()
+        );
+        assert_eq!(
+            &commit.as_bytes()[32..],
+            SHA512Commitment::new(&s, &r).commit().unwrap().as_bytes()
+        );
+        assert!(party.verify(&commit, &s, &r).unwrap())
+    }
+
+    #[cfg(feature = "sha3")]
+    #[test]
+    fn it_rejects_a_tampered_half() {
+        use crate::Commitment;
+        use sha3::Sha3_256;
+
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let party = DoubleHashCommitment::<_, Sha256, Sha3_256>::new(&s, &r);
+        let commit = party.commit().unwrap();
+
+        assert!(party.verify(&commit, &s, &r).unwrap());
+        for index in [0, 31, 32, 63] {
+            let mut tampered = commit.as_bytes().to_vec();
+            tampered[index] ^= 1;
+
+            assert!(!party.verify(&Commitment::from(tampered), &s, &r).unwrap());
+        }
+    }
+}
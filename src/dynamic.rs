@@ -0,0 +1,152 @@
+use crate::serializer::{BincodeSerializer, Serializer};
+use crate::{
+    check_commitment_len, check_randomness_len, length_prefix, Commitment, HashCommitmentError,
+    Result, DEFAULT_MIN_RANDOMNESS_LEN,
+};
+use alloc::boxed::Box;
+use alloc::string::ToString;
+use digest::DynDigest;
+use serde::Serialize;
+use subtle::ConstantTimeEq;
+
+/// An implementation of the Hash Commitment Scheme whose hash function is selected at runtime.
+///
+/// The hash function is chosen by name (see `from_name`), which suits applications reading it
+/// from their configuration. The secret is framed the same way as
+/// [`DigestCommitment`](crate::DigestCommitment) does, so that both produce the same commitments
+/// for the same hash function.
+///
+/// As the length of the commitments is only known at runtime, the commit and verify operations
+/// are provided as inherent methods rather than through
+/// [`HashCommitmentScheme`](crate::HashCommitmentScheme).
+///
+/// This implementation is only available when the `dyn-digest` feature is enabled.
+pub struct DynCommitment {
+    hasher: Box<dyn DynDigest>,
+    min_randomness: usize,
+}
+
+impl DynCommitment {
+    /// Creates a new scheme using the hash function with the given name.
+    ///
+    /// The supported names are `sha256` and `sha512`, as well as `sha3-256` when the `sha3`
+    /// feature is enabled. Any other name is rejected with a
+    /// [`HashCommitmentError::UnsupportedHash`].
+    pub fn from_name(name: &str) -> Result<DynCommitment> {
+        let hasher: Box<dyn DynDigest> = match name {
+            "sha256" => Box::<sha2::Sha256>::default(),
+            "sha512" => Box::<sha2::Sha512>::default(),
+            #[cfg(feature = "sha3")]
+            "sha3-256" => Box::<sha3::Sha3_256>::default(),
+            _ => return Err(HashCommitmentError::UnsupportedHash(name.to_string())),
+        };
+
+        Ok(DynCommitment {
+            hasher,
+            min_randomness: DEFAULT_MIN_RANDOMNESS_LEN,
+        })
+    }
+
+    /// Sets the minimum length in bytes of the random number accepted at commit time, which
+    /// defaults to [`DEFAULT_MIN_RANDOMNESS_LEN`].
+    pub fn with_min_randomness(mut self, min_randomness: usize) -> Self {
+        self.min_randomness = min_randomness;
+        self
+    }
+
+    /// Returns the length in bytes of the commitments produced by the scheme.
+    pub fn commitment_len(&self) -> usize {
+        self.hasher.output_size()
+    }
+
+    /// Creates the commitment of the secret s using the random number r.
+    ///
+    /// Returns a [`HashCommitmentError::InsufficientRandomness`] if the random number is too
+    /// short to hide the secret.
+    pub fn commit<T: Serialize + ?Sized>(&self, s: &T, r: &[u8]) -> Result<Commitment> {
+        check_randomness_len(r, self.min_randomness)?;
+
+        self.forge_commitment(s, r)
+    }
+
+    /// Creates the expected commitment using the prover's secret and random number.
+    /// Then, compares the expected commitment with the prover's one in constant time to verify
+    /// if the commitment holds.
+    ///
+    /// A commitment whose length differs from `commitment_len` is rejected with a
+    /// [`HashCommitmentError::LengthMismatch`] before any hashing.
+    pub fn verify<T: Serialize + ?Sized>(&self, com: &Commitment, s: &T, r: &[u8]) -> Result<bool> {
+        check_commitment_len(com, self.commitment_len())?;
+
+        let expected_commitment = self.forge_commitment(s, r)?;
+
+        Ok(expected_commitment.as_bytes().ct_eq(com.as_bytes()).into())
+    }
+
+    /// Forges a commitment by hashing the length-prefixed encoded secret s, followed by the
+    /// length-prefixed random number r, using a fresh clone of the selected hasher.
+    fn forge_commitment<T: Serialize + ?Sized>(&self, s: &T, r: &[u8]) -> Result<Commitment> {
+        let encoded_s = BincodeSerializer::serialize(s)?;
+
+        let mut hasher = self.hasher.box_clone();
+        hasher.update(&length_prefix(&encoded_s));
+        hasher.update(&encoded_s);
+        hasher.update(&length_prefix(r));
+        hasher.update(r);
+
+        Ok(Commitment::from(hasher.finalize().into_vec()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DynCommitment;
+    use crate::{HashCommitmentError, HashCommitmentScheme, SHA256Commitment, SHA512Commitment};
+
+    #[test]
+    fn it_selects_the_hash_function_by_name() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let scheme = DynCommitment::from_name("sha256").unwrap();
+        let commit = scheme.commit(&s, &r).unwrap();
+
+        assert_eq!(scheme.commitment_len(), 32);
+        assert_eq!(commit, SHA256Commitment::new(&s, &r).commit().unwrap());
+        assert!(scheme.verify(&commit, &s, &r).unwrap());
+        assert!(!scheme.verify(&commit, &s, b"4242424242424242").unwrap());
+
+        assert_eq!(
+            DynCommitment::from_name("sha512")
+                .unwrap()
+                .commit(&s, &r)
+                .unwrap(),
+            SHA512Commitment::new(&s, &r).commit().unwrap()
+        )
+    }
+
+    #[cfg(feature = "sha3")]
+    #[test]
+    fn it_selects_sha3_by_name() {
+        use crate::SHA3_256Commitment;
+
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        assert_eq!(
+            DynCommitment::from_name("sha3-256")
+                .unwrap()
+                .commit(&s, &r)
+                .unwrap(),
+            SHA3_256Commitment::new(&s, &r).commit().unwrap()
+        )
+    }
+
+    #[test]
+    fn it_rejects_an_unknown_hash_function() {
+        assert!(matches!(
+            DynCommitment::from_name("md5"),
+            Err(HashCommitmentError::UnsupportedHash(name)) if name == "md5"
+        ))
+    }
+}
@@ -0,0 +1,165 @@
+use crate::serializer::{BincodeSerializer, Serializer};
+use crate::{
+    check_commitment_len, check_randomness_len, length_prefix, Commitment, Opening, Result,
+    DEFAULT_MIN_RANDOMNESS_LEN,
+};
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use serde::Serialize;
+use sha2::digest::typenum::Unsigned;
+use sha2::{Digest, Sha256};
+use subtle::ConstantTimeEq;
+
+/// A canonical encoding of a value to a byte array, used as the secret part of a commitment
+/// preimage.
+///
+/// This trait lets callers commit to types which do not implement [`Serialize`], by providing
+/// the encoding manually. It is implemented for every [`Serialize`] type using bincode, which
+/// produces the same encoding as the default serializer of
+/// [`DigestCommitment`](crate::DigestCommitment).
+///
+/// The encoding must be deterministic, as both parties have to produce the same bytes for the
+/// commitment to hold.
+pub trait Encode {
+    /// Encodes the value to a byte array.
+    fn encode(&self) -> Result<Vec<u8>>;
+}
+
+impl<T: Serialize + ?Sized> Encode for T {
+    fn encode(&self) -> Result<Vec<u8>> {
+        BincodeSerializer::serialize(self)
+    }
+}
+
+/// An implementation of the Hash Commitment Scheme for secrets implementing [`Encode`] rather
+/// than [`Serialize`].
+///
+/// The secret is encoded using its [`Encode`] implementation, then framed the same way as
+/// [`DigestCommitment`](crate::DigestCommitment) does, so that both produce the same
+/// commitments for [`Serialize`] types.
+///
+/// As [`HashCommitmentScheme`](crate::HashCommitmentScheme) requires the secret to implement
+/// [`Serialize`], the commit and verify operations are provided as inherent methods.
+pub struct EncodeCommitment<'a, T: 'a + Encode + ?Sized, D: Digest> {
+    s: &'a T,
+    r: &'a [u8],
+    min_randomness: usize,
+    _digest: PhantomData<D>,
+}
+
+/// An implementation of the Hash Commitment Scheme for secrets implementing [`Encode`], using
+/// the SHA256 hash function.
+pub type SHA256EncodeCommitment<'a, T> = EncodeCommitment<'a, T, Sha256>;
+
+impl<'a, T: 'a + Encode + ?Sized, D: Digest> EncodeCommitment<'a, T, D> {
+    /// The length in bytes of the commitments produced by the scheme.
+    pub const COMMITMENT_LEN: usize = D::OutputSize::USIZE;
+
+    /// Creates a new party for the Commitment Scheme using its secret and random number.
+    pub fn new(s: &'a T, r: &'a [u8]) -> EncodeCommitment<'a, T, D> {
+        EncodeCommitment {
+            s,
+            r,
+            min_randomness: DEFAULT_MIN_RANDOMNESS_LEN,
+            _digest: PhantomData,
+        }
+    }
+
+    /// Sets the minimum length in bytes of the random number accepted at commit time, which
+    /// defaults to [`DEFAULT_MIN_RANDOMNESS_LEN`](crate::DEFAULT_MIN_RANDOMNESS_LEN).
+    pub fn with_min_randomness(mut self, min_randomness: usize) -> Self {
+        self.min_randomness = min_randomness;
+        self
+    }
+
+    /// Reveals the party's secret and random number, to be sent to the verifier during the open
+    /// phase.
+    pub fn open(&self) -> Opening<T>
+    where
+        T: Clone,
+    {
+        Opening::new(self.s.clone(), self.r.to_vec())
+    }
+
+    /// Creates the commitment used during the commit phase.
+    ///
+    /// Returns a [`HashCommitmentError::InsufficientRandomness`](crate::HashCommitmentError)
+    /// if the random number is too short to hide the secret.
+    pub fn commit(&self) -> Result<Commitment> {
+        check_randomness_len(self.r, self.min_randomness)?;
+
+        self.forge_commitment(self.s, self.r).map(Commitment::from)
+    }
+
+    /// Creates the expected commitment using the prover's secret and random number.
+    /// Then, compares the expected commitment with the prover's one in constant time to verify
+    /// if the commitment holds.
+    ///
+    /// A commitment whose length differs from `COMMITMENT_LEN` is rejected with a
+    /// [`HashCommitmentError::LengthMismatch`](crate::HashCommitmentError) before any hashing.
+    pub fn verify(&self, com: &Commitment, s: &T, r: &[u8]) -> Result<bool> {
+        check_commitment_len(com, Self::COMMITMENT_LEN)?;
+
+        let expected_commitment = self.forge_commitment(s, r)?;
+
+        Ok(expected_commitment.ct_eq(com.as_bytes()).into())
+    }
+
+    /// Forges a commitment by hashing the length-prefixed encoded secret s, followed by the
+    /// length-prefixed random number r.
+    fn forge_commitment(&self, s: &T, r: &[u8]) -> Result<Vec<u8>> {
+        let encoded_s = s.encode()?;
+
+        let hash = D::new()
+            .chain_update(length_prefix(&encoded_s))
+            .chain_update(encoded_s.as_slice())
+            .chain_update(length_prefix(r))
+            .chain_update(r)
+            .finalize();
+
+        Ok(hash.to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Encode, SHA256EncodeCommitment};
+    use crate::{HashCommitmentScheme, Result, SHA256Commitment};
+
+    /// A secret which does not implement `Serialize`, encoded as a little-endian u32.
+    struct Counter(u32);
+
+    impl Encode for Counter {
+        fn encode(&self) -> Result<Vec<u8>> {
+            Ok(self.0.to_le_bytes().to_vec())
+        }
+    }
+
+    #[test]
+    fn it_commits_with_a_custom_encoding() {
+        let s = Counter(42);
+        let r: [u8; 16] = *b"2424242424242424";
+
+        // Commit phase.
+        let party = SHA256EncodeCommitment::new(&s, &r);
+        let commit = party.commit().unwrap();
+
+        // Verification phase.
+        assert!(party.verify(&commit, &s, &r).unwrap());
+        assert!(!party.verify(&commit, &Counter(24), &r).unwrap());
+
+        // A u32 is encoded by bincode the same way, so the commitments match.
+        assert_eq!(commit, SHA256Commitment::new(&42u32, &r).commit().unwrap())
+    }
+
+    #[test]
+    fn it_commits_like_digest_commitment_for_serialize_types() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        assert_eq!(
+            SHA256EncodeCommitment::new(&s, &r).commit().unwrap(),
+            SHA256Commitment::new(&s, &r).commit().unwrap()
+        )
+    }
+}
@@ -0,0 +1,140 @@
+//! The encoding of the fields framed into the commitment preimages.
+//!
+//! The framed commitment schemes of this crate, such as
+//! [`DigestCommitment`](crate::DigestCommitment) and the tagged commitments of
+//! [`BytesCommitment`](crate::BytesCommitment), encode their preimage using these helpers, so
+//! that they all agree on how integers and variable-length fields are encoded. Integers are
+//! encoded as big-endian u64, and variable-length fields are prefixed with their length encoded
+//! the same way. The raw and chunked commitments of [`BytesCommitment`](crate::BytesCommitment)
+//! and the [`FramingVersion::V1`](crate::FramingVersion::V1) commitments are unframed, and do
+//! not use them.
+
+use alloc::vec::Vec;
+
+// Lengths are widened to u64 before being encoded, so that the prefixes are the same on 32-bit
+// and 64-bit targets. The build fails on a target whose lengths would not fit, rather than
+// silently truncating them.
+const _: () = assert!(usize::BITS <= u64::BITS);
+
+/// Encodes an integer, such as an index or a timestamp, as a big-endian u64.
+pub fn encode_u64_be(value: u64) -> [u8; 8] {
+    value.to_be_bytes()
+}
+
+/// Encodes a variable-length field, prefixed with its length.
+pub fn encode_len_prefixed(field: &[u8]) -> Vec<u8> {
+    PreimageBuilder::new().field(field).finish()
+}
+
+/// Encodes a length or a count as a big-endian u64, whatever the pointer width of the target.
+pub(crate) fn encode_len(len: usize) -> [u8; 8] {
+    encode_u64_be(len as u64)
+}
+
+/// Returns the length prefix written in front of each field of a commitment preimage.
+///
+/// Each field is prefixed with its length encoded as a fixed-width, big-endian u64, so that two
+/// different pairs of fields can never be framed into the same preimage. Without it, moving a
+/// byte from the tail of the encoded secret to the front of the random number would produce
+/// the same commitment.
+pub(crate) fn length_prefix(field: &[u8]) -> [u8; 8] {
+    encode_len(field.len())
+}
+
+/// A builder accumulating the framed fields of a commitment preimage.
+///
+/// The preimage of a [`DigestCommitment`](crate::DigestCommitment) without domain is built by
+/// adding the encoded secret, then the random number, as fields.
+#[derive(Debug, Clone, Default)]
+pub struct PreimageBuilder {
+    preimage: Vec<u8>,
+}
+
+impl PreimageBuilder {
+    /// Creates a new builder holding an empty preimage.
+    pub fn new() -> PreimageBuilder {
+        PreimageBuilder::default()
+    }
+
+    /// Appends a variable-length field, prefixed with its length.
+    pub fn field(mut self, field: &[u8]) -> Self {
+        self.preimage.extend_from_slice(&length_prefix(field));
+        self.preimage.extend_from_slice(field);
+        self
+    }
+
+    /// Appends an integer encoded as a big-endian u64, without any prefix as its length is
+    /// fixed.
+    pub fn u64(mut self, value: u64) -> Self {
+        self.preimage.extend_from_slice(&encode_u64_be(value));
+        self
+    }
+
+    /// Returns the preimage, to be fed to the hash function.
+    pub fn finish(self) -> Vec<u8> {
+        self.preimage
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{encode_len_prefixed, encode_u64_be, PreimageBuilder};
+
+    #[test]
+    fn it_encodes_integers_as_big_endian_u64() {
+        assert_eq!(encode_u64_be(0), [0; 8]);
+        assert_eq!(encode_u64_be(300), [0, 0, 0, 0, 0, 0, 1, 44]);
+        assert_eq!(
+            encode_u64_be(u64::MAX - 1),
+            [255, 255, 255, 255, 255, 255, 255, 254]
+        )
+    }
+
+    #[test]
+    fn it_prefixes_fields_with_their_length() {
+        assert_eq!(encode_len_prefixed(b""), [0; 8]);
+        assert_eq!(
+            encode_len_prefixed(b"4242"),
+            [0, 0, 0, 0, 0, 0, 0, 4, 52, 50, 52, 50]
+        )
+    }
+
+    #[test]
+    fn it_builds_a_preimage() {
+        let preimage = PreimageBuilder::new()
+            .field(b"42")
+            .u64(24)
+            .field(b"")
+            .finish();
+
+        assert_eq!(
+            preimage,
+            [
+                0, 0, 0, 0, 0, 0, 0, 2, 52, 50, // field
+                0, 0, 0, 0, 0, 0, 0, 24, // u64
+                0, 0, 0, 0, 0, 0, 0, 0, // empty field
+            ]
+        )
+    }
+
+    /// The builder frames the preimage the same way as the commitment schemes.
+    #[test]
+    fn it_builds_the_preimage_of_a_commitment() {
+        use crate::serializer::{BincodeSerializer, Serializer};
+        use crate::{HashCommitmentScheme, SHA256Commitment};
+        use sha2::{Digest, Sha256};
+
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let preimage = PreimageBuilder::new()
+            .field(&BincodeSerializer::serialize(&s).unwrap())
+            .field(&r)
+            .finish();
+
+        assert_eq!(
+            Sha256::digest(preimage).as_slice(),
+            SHA256Commitment::new(&s, &r).commit().unwrap().as_bytes()
+        )
+    }
+}
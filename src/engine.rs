@@ -0,0 +1,162 @@
+use crate::serializer::{BincodeSerializer, Serializer};
+use crate::{
+    check_commitment_len, check_randomness_len, framing_hasher, length_prefix, Commitment, Result,
+    DEFAULT_MIN_RANDOMNESS_LEN,
+};
+use serde::Serialize;
+use sha2::digest::typenum::Unsigned;
+use sha2::{Digest, Sha256};
+use subtle::ConstantTimeEq;
+
+/// A reusable engine forging many commitments with the hash function D.
+///
+/// Unlike a [`DigestCommitment`](crate::DigestCommitment), which creates a new hasher for each
+/// commitment, the engine initializes a template hasher once, fed with the domain-separation tag
+/// if there is one, and clones it for each commitment. This saves the setup of the hasher when
+/// committing in tight loops, and the commitments produced are the same as the ones of the
+/// equivalent [`DigestCommitment`](crate::DigestCommitment).
+#[derive(Clone)]
+pub struct CommitmentEngine<D: Digest + Clone> {
+    template: D,
+    min_randomness: usize,
+}
+
+/// A commitment engine using the SHA256 hash function.
+pub type SHA256CommitmentEngine = CommitmentEngine<Sha256>;
+
+impl<D: Digest + Clone> CommitmentEngine<D> {
+    /// The length in bytes of the commitments forged by the engine.
+    pub const COMMITMENT_LEN: usize = D::OutputSize::USIZE;
+
+    /// Creates a new engine, whose template hasher has not been fed with any data.
+    pub fn new() -> CommitmentEngine<D> {
+        CommitmentEngine {
+            template: D::new(),
+            min_randomness: DEFAULT_MIN_RANDOMNESS_LEN,
+        }
+    }
+
+    /// Creates a new engine binding the given domain-separation tag into every commitment, the
+    /// same way as [`DigestCommitment::with_domain`](crate::DigestCommitment::with_domain).
+    ///
+    /// The engine forges the same commitments as a [`DomainScheme`](crate::DomainScheme) created
+    /// with the same domain under the default serializer.
+    pub fn with_domain(domain: &[u8]) -> CommitmentEngine<D> {
+        CommitmentEngine {
+            template: framing_hasher(Some(domain), None),
+            min_randomness: DEFAULT_MIN_RANDOMNESS_LEN,
+        }
+    }
+
+    /// Sets the minimum length in bytes of the random number accepted at commit time, which
+    /// defaults to [`DEFAULT_MIN_RANDOMNESS_LEN`](crate::DEFAULT_MIN_RANDOMNESS_LEN).
+    pub fn with_min_randomness(mut self, min_randomness: usize) -> Self {
+        self.min_randomness = min_randomness;
+        self
+    }
+
+    /// Forges the commitment to the secret s and random number r.
+    ///
+    /// Returns a [`HashCommitmentError::InsufficientRandomness`](crate::HashCommitmentError)
+    /// if the random number is too short to hide the secret.
+    pub fn commit<T: Serialize + ?Sized>(&self, s: &T, r: &[u8]) -> Result<Commitment> {
+        check_randomness_len(r, self.min_randomness)?;
+
+        self.forge_commitment(s, r)
+    }
+
+    /// Verifies that the secret s and random number r open the commitment, comparing the
+    /// recomputed commitment with the given one in constant time.
+    ///
+    /// A commitment whose length differs from `COMMITMENT_LEN` is rejected with a
+    /// [`HashCommitmentError::LengthMismatch`](crate::HashCommitmentError) before any hashing.
+    pub fn verify<T: Serialize + ?Sized>(&self, com: &Commitment, s: &T, r: &[u8]) -> Result<bool> {
+        check_commitment_len(com, Self::COMMITMENT_LEN)?;
+
+        let expected_commitment = self.forge_commitment(s, r)?;
+
+        Ok(expected_commitment.as_bytes().ct_eq(com.as_bytes()).into())
+    }
+
+    /// Forges a commitment from a clone of the template hasher, fed with the length-prefixed
+    /// encoded secret, then the length-prefixed random number.
+    fn forge_commitment<T: Serialize + ?Sized>(&self, s: &T, r: &[u8]) -> Result<Commitment> {
+        let mut hasher = self.template.clone();
+
+        BincodeSerializer::update_digest(s, &mut hasher)?;
+
+        let hash = hasher
+            .chain_update(length_prefix(r))
+            .chain_update(r)
+            .finalize();
+
+        Ok(Commitment::from(hash.to_vec()))
+    }
+}
+
+impl<D: Digest + Clone> Default for CommitmentEngine<D> {
+    fn default() -> Self {
+        CommitmentEngine::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CommitmentEngine, SHA256CommitmentEngine};
+    use crate::{DigestCommitment, HashCommitmentError, HashCommitmentScheme, SHA256Commitment};
+    use sha2::Sha512;
+
+    #[test]
+    fn it_matches_the_direct_commitments() {
+        let r: [u8; 16] = *b"2424242424242424";
+        let engine = SHA256CommitmentEngine::new();
+
+        for i in 0..8u8 {
+            let s: [u8; 4] = [i, 50, 52, 50];
+
+            let commit = engine.commit(&s, &r).unwrap();
+
+            assert_eq!(commit, SHA256Commitment::new(&s, &r).commit().unwrap());
+            assert!(engine.verify(&commit, &s, &r).unwrap());
+            assert!(!engine.verify(&commit, &[i, 50, 52, 51], &r).unwrap());
+        }
+    }
+
+    #[test]
+    fn it_matches_the_direct_commitments_with_a_domain() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let engine = CommitmentEngine::<Sha512>::with_domain(b"hashcom-rs/test");
+
+        assert_eq!(
+            engine.commit(&s, &r).unwrap(),
+            DigestCommitment::<_, Sha512>::with_domain(&s, &r, b"hashcom-rs/test")
+                .commit()
+                .unwrap()
+        )
+    }
+
+    #[test]
+    fn it_rejects_short_randomness_and_malformed_commitments() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let engine = SHA256CommitmentEngine::default();
+
+        assert!(matches!(
+            engine.commit(&s, b"24"),
+            Err(HashCommitmentError::InsufficientRandomness { got: 2, min: 16 })
+        ));
+        assert!(engine
+            .clone()
+            .with_min_randomness(2)
+            .commit(&s, b"24")
+            .is_ok());
+        assert!(matches!(
+            engine.verify(&vec![0u8; 16].into(), &s, b"24"),
+            Err(HashCommitmentError::LengthMismatch {
+                expected: 32,
+                actual: 16
+            })
+        ))
+    }
+}
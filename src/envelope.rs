@@ -0,0 +1,139 @@
+//! The wire format of the two phases of a Hash Commitment Scheme.
+//!
+//! A [`CommitEnvelope`] is sent during the commit phase and holds the commitment along with the
+//! scheme it was forged with, but never the secret. An [`OpenEnvelope`] is sent during the open
+//! phase and holds the encoded secret and the random number. Both can be serialized with any
+//! serde format, and are tied together by
+//! [`DigestCommitment::verify_envelope`](crate::DigestCommitment::verify_envelope).
+//!
+//! This module is only available when the `serde-derive` feature is enabled.
+
+use crate::Commitment;
+use alloc::string::String;
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+
+/// The message sent by the prover during the commit phase.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommitEnvelope {
+    commitment: Commitment,
+    scheme_id: String,
+    len: usize,
+}
+
+impl CommitEnvelope {
+    pub(crate) fn new(commitment: Commitment, scheme_id: &str) -> CommitEnvelope {
+        CommitEnvelope {
+            len: commitment.len(),
+            commitment,
+            scheme_id: scheme_id.into(),
+        }
+    }
+
+    /// Returns the commitment forged by the prover.
+    pub fn commitment(&self) -> &Commitment {
+        &self.commitment
+    }
+
+    /// Returns the name of the hash function the commitment was forged with, as given by
+    /// [`NamedDigest::NAME`](crate::NamedDigest::NAME).
+    pub fn scheme_id(&self) -> &str {
+        &self.scheme_id
+    }
+
+    /// Returns the length in bytes announced for the commitment.
+    pub fn commitment_len(&self) -> usize {
+        self.len
+    }
+}
+
+/// The message sent by the prover during the open phase.
+///
+/// The secret is sent encoded, so that the verifier does not need to decode it to verify the
+/// commitment.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OpenEnvelope {
+    #[serde(with = "serde_bytes")]
+    secret_bytes: Vec<u8>,
+    #[serde(with = "serde_bytes")]
+    randomness: Vec<u8>,
+}
+
+impl OpenEnvelope {
+    pub(crate) fn new(secret_bytes: Vec<u8>, randomness: Vec<u8>) -> OpenEnvelope {
+        OpenEnvelope {
+            secret_bytes,
+            randomness,
+        }
+    }
+
+    /// Returns the secret revealed by the prover, encoded by the serializer of the scheme.
+    pub fn secret_bytes(&self) -> &[u8] {
+        &self.secret_bytes
+    }
+
+    /// Returns the random number revealed by the prover.
+    pub fn randomness(&self) -> &[u8] {
+        &self.randomness
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CommitEnvelope, OpenEnvelope};
+    use crate::{DigestCommitment, HashCommitmentError, SHA256Commitment, SHA512Commitment};
+
+    #[test]
+    fn it_verifies_envelopes_sent_as_json() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        // Commit and open phases, on the prover's side.
+        let prover = SHA256Commitment::new(&s, &r);
+        let commit_json = serde_json::to_string(&prover.commit_envelope().unwrap()).unwrap();
+        let open_json = serde_json::to_string(&prover.open_envelope().unwrap()).unwrap();
+
+        // Verification phase, on the verifier's side, which never holds the secret.
+        let commit_env: CommitEnvelope = serde_json::from_str(&commit_json).unwrap();
+        let open_env: OpenEnvelope = serde_json::from_str(&open_json).unwrap();
+        let verifier = SHA256Commitment::new(&[0u8; 4], &[]);
+
+        assert_eq!(commit_env.scheme_id(), "sha256");
+        assert_eq!(commit_env.commitment_len(), 32);
+        assert_eq!(open_env.secret_bytes(), s);
+        assert!(verifier.verify_envelope(&commit_env, &open_env).unwrap());
+
+        let tampered_env = OpenEnvelope::new(b"4243".to_vec(), r.to_vec());
+
+        assert!(!verifier
+            .verify_envelope(&commit_env, &tampered_env)
+            .unwrap())
+    }
+
+    #[test]
+    fn it_rejects_envelopes_of_another_scheme() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let prover = SHA512Commitment::new(&s, &r);
+        let commit_env = prover.commit_envelope().unwrap();
+        let open_env = prover.open_envelope().unwrap();
+        let truncated_env = CommitEnvelope::new(
+            commit_env.commitment().as_bytes()[..32].into(),
+            commit_env.scheme_id(),
+        );
+
+        assert!(matches!(
+            SHA256Commitment::new(&s, &r).verify_envelope(&commit_env, &open_env),
+            Err(HashCommitmentError::SchemeMismatch)
+        ));
+        assert!(matches!(
+            DigestCommitment::<_, sha2::Sha512>::new(&s, &r)
+                .verify_envelope(&truncated_env, &open_env),
+            Err(HashCommitmentError::LengthMismatch {
+                expected: 64,
+                actual: 32
+            })
+        ))
+    }
+}
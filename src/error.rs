@@ -0,0 +1,250 @@
+use core::fmt;
+
+/// The errors which can occur while using a Hash Commitment Scheme.
+///
+/// This enum is marked as non-exhaustive as new failure cases may be added as the commitment
+/// schemes provided by this crate evolve.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum HashCommitmentError {
+    /// The secret could not be encoded to a byte array before being hashed.
+    Serialization(bincode::error::EncodeError),
+
+    /// The secret could not be encoded to JSON before being hashed.
+    #[cfg(feature = "json")]
+    Json(serde_json::Error),
+
+    /// The secret could not be encoded with postcard before being hashed.
+    #[cfg(feature = "postcard")]
+    Postcard(postcard::Error),
+
+    /// The string could not be decoded as a hex-encoded commitment.
+    InvalidHex(base16ct::Error),
+
+    /// The string could not be decoded as a Base64-encoded commitment.
+    #[cfg(feature = "base64")]
+    InvalidBase64(base64::DecodeError),
+
+    /// A Merkle tree can not be built without any secret to commit to.
+    EmptyMerkleTree,
+
+    /// The random number is too short to hide the secret it is committed with.
+    InsufficientRandomness { got: usize, min: usize },
+
+    /// The commitment does not have the length of the commitments produced by the scheme.
+    LengthMismatch { expected: usize, actual: usize },
+
+    /// The commitment is made of zeros only, which a hash function does not output in practice.
+    DegenerateCommitment,
+
+    /// The commitment can not be truncated to the requested length, which must lie between
+    /// the minimum and the output size of the hash function.
+    InvalidTruncation { len: usize, min: usize, max: usize },
+
+    /// The commitment was forged using a framing version which is unknown or not accepted by
+    /// the verifier.
+    UnsupportedVersion(u8),
+
+    /// The hash function selected by name is not supported.
+    #[cfg(feature = "dyn-digest")]
+    UnsupportedHash(alloc::string::String),
+
+    /// The opening blob could not be parsed.
+    InvalidBlob,
+
+    /// The commitment was forged with another hash function than the verifier's one.
+    SchemeMismatch,
+
+    /// The opening was created under another domain or associated data than the verifier's
+    /// ones.
+    ContextMismatch,
+
+    /// A commitment has already been recorded in the transcript.
+    AlreadyCommitted,
+
+    /// The opening can not be revealed before the commitment has been recorded.
+    MissingCommitment,
+
+    /// The opening revealed by the prover does not match the recorded commitment.
+    InvalidOpening,
+
+    /// A commitment was opened by another secret or random number than the ones it was forged
+    /// with.
+    #[cfg(feature = "testing")]
+    BindingViolation,
+
+    /// The secret could not be read from its source.
+    #[cfg(feature = "std")]
+    Io(std::io::Error),
+
+    /// The task forging the commitment in the background panicked or was cancelled.
+    #[cfg(feature = "tokio")]
+    Join(tokio::task::JoinError),
+}
+
+/// A specialized [`Result`](core::result::Result) type for the Hash Commitment Schemes.
+pub type Result<T> = core::result::Result<T, HashCommitmentError>;
+
+impl HashCommitmentError {
+    /// Returns true if the error occurred while encoding the secret.
+    pub(crate) fn is_serialization(&self) -> bool {
+        match self {
+            HashCommitmentError::Serialization(_) => true,
+            #[cfg(feature = "json")]
+            HashCommitmentError::Json(_) => true,
+            #[cfg(feature = "postcard")]
+            HashCommitmentError::Postcard(_) => true,
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for HashCommitmentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HashCommitmentError::Serialization(e) => write!(f, "failed to serialize secret: {e}"),
+            #[cfg(feature = "json")]
+            HashCommitmentError::Json(e) => write!(f, "failed to serialize secret to JSON: {e}"),
+            #[cfg(feature = "postcard")]
+            HashCommitmentError::Postcard(e) => {
+                write!(f, "failed to serialize secret with postcard: {e}")
+            }
+            HashCommitmentError::InvalidHex(e) => write!(f, "invalid hex commitment: {e}"),
+            #[cfg(feature = "base64")]
+            HashCommitmentError::InvalidBase64(e) => write!(f, "invalid base64 commitment: {e}"),
+            HashCommitmentError::EmptyMerkleTree => {
+                write!(f, "cannot build a merkle tree without leaves")
+            }
+            HashCommitmentError::InsufficientRandomness { got, min } => write!(
+                f,
+                "insufficient randomness: expected at least {min} bytes, got {got}"
+            ),
+            HashCommitmentError::LengthMismatch { expected, actual } => write!(
+                f,
+                "invalid commitment length: expected {expected} bytes, got {actual}"
+            ),
+            HashCommitmentError::DegenerateCommitment => {
+                write!(f, "degenerate commitment: all bytes are zero")
+            }
+            HashCommitmentError::InvalidTruncation { len, min, max } => write!(
+                f,
+                "invalid truncation: expected between {min} and {max} bytes, got {len}"
+            ),
+            HashCommitmentError::UnsupportedVersion(version) => {
+                write!(f, "unsupported commitment version: {version}")
+            }
+            #[cfg(feature = "dyn-digest")]
+            HashCommitmentError::UnsupportedHash(name) => {
+                write!(f, "unsupported hash function: {name}")
+            }
+            HashCommitmentError::InvalidBlob => write!(f, "malformed opening blob"),
+            HashCommitmentError::SchemeMismatch => {
+                write!(f, "commitment was forged with another hash function")
+            }
+            HashCommitmentError::ContextMismatch => {
+                write!(
+                    f,
+                    "opening was created under another domain or associated data"
+                )
+            }
+            HashCommitmentError::AlreadyCommitted => write!(f, "commitment already recorded"),
+            HashCommitmentError::MissingCommitment => {
+                write!(f, "cannot open before a commitment is recorded")
+            }
+            HashCommitmentError::InvalidOpening => {
+                write!(f, "opening does not match the commitment")
+            }
+            #[cfg(feature = "testing")]
+            HashCommitmentError::BindingViolation => {
+                write!(f, "commitment opened by another secret or random number")
+            }
+            #[cfg(feature = "std")]
+            HashCommitmentError::Io(e) => write!(f, "failed to read secret: {e}"),
+            #[cfg(feature = "tokio")]
+            HashCommitmentError::Join(e) => write!(f, "failed to run commitment task: {e}"),
+        }
+    }
+}
+
+/// The [`std::error::Error`] trait is only implemented when the `std` feature is enabled.
+#[cfg(feature = "std")]
+impl std::error::Error for HashCommitmentError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            HashCommitmentError::Serialization(e) => Some(e),
+            #[cfg(feature = "json")]
+            HashCommitmentError::Json(e) => Some(e),
+            #[cfg(feature = "postcard")]
+            HashCommitmentError::Postcard(e) => Some(e),
+            HashCommitmentError::InvalidHex(e) => Some(e),
+            #[cfg(feature = "base64")]
+            HashCommitmentError::InvalidBase64(e) => Some(e),
+            HashCommitmentError::EmptyMerkleTree => None,
+            HashCommitmentError::InsufficientRandomness { .. } => None,
+            HashCommitmentError::LengthMismatch { .. } => None,
+            HashCommitmentError::DegenerateCommitment => None,
+            HashCommitmentError::InvalidTruncation { .. } => None,
+            HashCommitmentError::UnsupportedVersion(_) => None,
+            #[cfg(feature = "dyn-digest")]
+            HashCommitmentError::UnsupportedHash(_) => None,
+            HashCommitmentError::InvalidBlob => None,
+            HashCommitmentError::SchemeMismatch => None,
+            HashCommitmentError::ContextMismatch => None,
+            HashCommitmentError::AlreadyCommitted => None,
+            HashCommitmentError::MissingCommitment => None,
+            HashCommitmentError::InvalidOpening => None,
+            #[cfg(feature = "testing")]
+            HashCommitmentError::BindingViolation => None,
+            HashCommitmentError::Io(e) => Some(e),
+            #[cfg(feature = "tokio")]
+            HashCommitmentError::Join(e) => Some(e),
+        }
+    }
+}
+
+impl From<bincode::error::EncodeError> for HashCommitmentError {
+    fn from(e: bincode::error::EncodeError) -> Self {
+        HashCommitmentError::Serialization(e)
+    }
+}
+
+#[cfg(feature = "json")]
+impl From<serde_json::Error> for HashCommitmentError {
+    fn from(e: serde_json::Error) -> Self {
+        HashCommitmentError::Json(e)
+    }
+}
+
+#[cfg(feature = "postcard")]
+impl From<postcard::Error> for HashCommitmentError {
+    fn from(e: postcard::Error) -> Self {
+        HashCommitmentError::Postcard(e)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for HashCommitmentError {
+    fn from(e: std::io::Error) -> Self {
+        HashCommitmentError::Io(e)
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl From<tokio::task::JoinError> for HashCommitmentError {
+    fn from(e: tokio::task::JoinError) -> Self {
+        HashCommitmentError::Join(e)
+    }
+}
+
+impl From<base16ct::Error> for HashCommitmentError {
+    fn from(e: base16ct::Error) -> Self {
+        HashCommitmentError::InvalidHex(e)
+    }
+}
+
+#[cfg(feature = "base64")]
+impl From<base64::DecodeError> for HashCommitmentError {
+    fn from(e: base64::DecodeError) -> Self {
+        HashCommitmentError::InvalidBase64(e)
+    }
+}
@@ -0,0 +1,56 @@
+/// A random number whose length in bytes is fixed at the type level.
+///
+/// Parties created from a fixed random number (see `DigestCommitment::with_fixed_random`) can not
+/// be given a random number of the wrong length: the mismatch is caught by the compiler instead
+/// of at commit time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedRandomness<const N: usize>(pub [u8; N]);
+
+impl<const N: usize> FixedRandomness<N> {
+    /// The length in bytes of the random number.
+    pub const LEN: usize = N;
+
+    /// Creates a new fixed random number from a byte array.
+    pub fn new(r: [u8; N]) -> FixedRandomness<N> {
+        FixedRandomness(r)
+    }
+
+    /// Returns the random number as a byte slice.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl<const N: usize> From<[u8; N]> for FixedRandomness<N> {
+    fn from(r: [u8; N]) -> Self {
+        FixedRandomness(r)
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for FixedRandomness<N> {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FixedRandomness;
+    use crate::{HashCommitmentScheme, SHA256Commitment};
+
+    #[test]
+    fn it_commits_with_a_fixed_random_number() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r = FixedRandomness::<32>::new(*b"24242424242424242424242424242424");
+
+        let party = SHA256Commitment::with_fixed_random(&s, &r);
+        let commit = party.commit().unwrap();
+
+        assert_eq!(FixedRandomness::<32>::LEN, 32);
+        assert_eq!(
+            commit,
+            SHA256Commitment::new(&s, r.as_bytes()).commit().unwrap()
+        );
+        assert!(party.verify(&commit, &s, r.as_bytes()).unwrap())
+    }
+}
@@ -0,0 +1,110 @@
+use crate::{length_prefix, Commitment};
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use sha2::{Digest, Sha256};
+
+/// An incremental commitment to a raw secret, fed the same way as a [`Digest`].
+///
+/// The secret does not need to be known up front, nor to be encoded with serde: its bytes are
+/// accumulated by successive calls to `update`, and the random number is set using
+/// `set_randomness`. Both fields are framed with their length when the commitment is finalized,
+/// so the commitment is the digest of the preimage built by
+/// [`PreimageBuilder`](crate::encoding::PreimageBuilder) from the secret, then the random number.
+///
+/// As the length of the secret prefixes it in the preimage, the accumulated bytes are kept until
+/// the commitment is finalized.
+pub struct CommitmentHasher<D: Digest> {
+    s: Vec<u8>,
+    r: Vec<u8>,
+    _digest: PhantomData<D>,
+}
+
+/// An incremental commitment using the SHA256 hash function.
+pub type SHA256CommitmentHasher = CommitmentHasher<Sha256>;
+
+impl<D: Digest> CommitmentHasher<D> {
+    /// Creates a new commitment, with an empty secret and an empty random number.
+    pub fn new() -> CommitmentHasher<D> {
+        CommitmentHasher {
+            s: Vec::new(),
+            r: Vec::new(),
+            _digest: PhantomData,
+        }
+    }
+
+    /// Appends the given bytes to the secret.
+    pub fn update(&mut self, data: &[u8]) {
+        self.s.extend_from_slice(data);
+    }
+
+    /// Sets the random number hiding the secret, replacing any previous one.
+    ///
+    /// Its length is not checked, but it should be at least
+    /// [`DEFAULT_MIN_RANDOMNESS_LEN`](crate::DEFAULT_MIN_RANDOMNESS_LEN) bytes long to hide the
+    /// secret.
+    pub fn set_randomness(&mut self, r: &[u8]) {
+        self.r = r.to_vec();
+    }
+
+    /// Forges the commitment to the accumulated secret and the random number.
+    pub fn finalize(self) -> Commitment {
+        let hash = D::new()
+            .chain_update(length_prefix(&self.s))
+            .chain_update(&self.s)
+            .chain_update(length_prefix(&self.r))
+            .chain_update(&self.r)
+            .finalize();
+
+        Commitment::from(hash.to_vec())
+    }
+}
+
+impl<D: Digest> Default for CommitmentHasher<D> {
+    fn default() -> Self {
+        CommitmentHasher::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SHA256CommitmentHasher;
+    use crate::encoding::PreimageBuilder;
+    use sha2::{Digest, Sha256};
+
+    #[test]
+    fn it_commits_to_a_secret_fed_in_several_updates() {
+        let s = b"4242BDBD";
+        let r = b"2424242424242424";
+
+        let mut hasher = SHA256CommitmentHasher::new();
+        hasher.update(&s[..3]);
+        hasher.set_randomness(r);
+        hasher.update(&s[3..]);
+        let commit = hasher.finalize();
+
+        let mut one_shot_hasher = SHA256CommitmentHasher::default();
+        one_shot_hasher.update(s);
+        one_shot_hasher.set_randomness(r);
+
+        assert_eq!(commit, one_shot_hasher.finalize());
+        assert_eq!(
+            commit.as_bytes(),
+            Sha256::digest(PreimageBuilder::new().field(s).field(r).finish()).as_slice()
+        )
+    }
+
+    /// Here, the last byte of the secret is moved to the front of the random number, which
+    /// keeps the raw concatenation of both fields unchanged.
+    #[test]
+    fn it_frames_the_secret_and_the_randomness() {
+        let mut hasher = SHA256CommitmentHasher::new();
+        hasher.update(b"4242");
+        hasher.set_randomness(b"2424242424242424");
+
+        let mut shifted_hasher = SHA256CommitmentHasher::new();
+        shifted_hasher.update(b"424");
+        shifted_hasher.set_randomness(b"22424242424242424");
+
+        assert_ne!(hasher.finalize(), shifted_hasher.finalize())
+    }
+}
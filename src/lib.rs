@@ -1,6 +1,111 @@
-use bincode::Result;
+//! A fast, minimal but yet extensible framework for building and using hash commitment schemes.
+//!
+//! ### `no_std` support
+//! The crate only depends on the standard library through its default `std` feature. Disabling
+//! the default features makes it usable in `no_std` environments, as long as an allocator is
+//! available since commitments and encoded secrets are stored in a `Vec`.
+//!
+//! Secrets are encoded with bincode 2 (which supports `no_std`), using its legacy configuration
+//! so that the encoding is the same as the one of bincode 1.x used by previous versions of
+//! this crate. This configuration is pinned as [`serializer::BINCODE_CONFIG`]. Features relying
+//! on the operating system, such as `rand` and `rayon`, enable the `std` feature.
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+mod bit;
+mod blob;
+mod builder;
+mod bytes;
+mod commitment;
+mod domain;
+mod double;
+#[cfg(feature = "dyn-digest")]
+mod dynamic;
+mod encode;
+pub mod encoding;
+mod engine;
+#[cfg(feature = "serde-derive")]
+pub mod envelope;
+pub mod error;
+mod fixed;
+mod hasher;
+#[cfg(feature = "hmac")]
+mod mac;
+pub mod merkle;
+mod mixed;
+mod opening;
+#[cfg(debug_assertions)]
+mod outcome;
+#[cfg(feature = "zeroize")]
+mod owned;
+pub mod protocol;
+#[cfg(feature = "rand")]
+mod salted;
+pub mod serializer;
+mod shared;
+pub mod slice;
+#[cfg(feature = "testing")]
+pub mod testing;
+mod timed;
+pub mod vector;
+mod versioned;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+pub use bit::{BitCommitment, SHA256BitCommitment};
+pub use blob::{verify_blob, NamedDigest};
+pub use builder::CommitmentBuilder;
+pub use bytes::{raw_commit, raw_verify, BytesCommitment, SHA256BytesCommitment};
+pub use commitment::Commitment;
+use core::fmt;
+use core::marker::PhantomData;
+pub use domain::{DomainScheme, SHA256DomainScheme};
+pub use double::DoubleHashCommitment;
+#[cfg(feature = "dyn-digest")]
+pub use dynamic::DynCommitment;
+pub use encode::{Encode, EncodeCommitment, SHA256EncodeCommitment};
+pub(crate) use encoding::{encode_len, length_prefix};
+pub use engine::{CommitmentEngine, SHA256CommitmentEngine};
+pub use error::{HashCommitmentError, Result};
+pub use fixed::FixedRandomness;
+pub use hasher::{CommitmentHasher, SHA256CommitmentHasher};
+#[cfg(feature = "hmac")]
+pub use mac::HmacCommitment;
+pub use mixed::{OwnedSecretCommitment, OwnedSecretSHA256Commitment};
+pub use opening::Opening;
+#[cfg(debug_assertions)]
+pub use outcome::{MismatchHint, VerifyOutcome};
+#[cfg(feature = "zeroize")]
+pub use owned::{OwnedDigestCommitment, OwnedSHA256Commitment};
+#[cfg(feature = "rand")]
+pub use salted::{SHA256SaltedCommitment, SaltedCommitment, SALT_LEN};
 use serde::Serialize;
-use sha2::{Digest, Sha256};
+use serializer::{BincodeSerializer, Serializer};
+use sha2::digest::typenum::Unsigned;
+use sha2::{Digest, Sha256, Sha512};
+pub use shared::{SharedDigestCommitment, SharedSHA256Commitment};
+use subtle::ConstantTimeEq;
+pub use timed::{SHA256TimedCommitment, TimedCommitment};
+pub use versioned::{FramingVersion, SHA256VersionedCommitment, VersionedCommitment};
+
+#[cfg(feature = "serde-derive")]
+use envelope::{CommitEnvelope, OpenEnvelope};
+#[cfg(feature = "rand")]
+use rand::{rngs::OsRng, rngs::StdRng, RngCore, SeedableRng};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+#[cfg(feature = "blake2")]
+use blake2::Blake2b512;
+#[cfg(feature = "hmac")]
+use hmac::{Hmac, Mac};
+#[cfg(feature = "keccak")]
+use sha3::Keccak256;
+#[cfg(feature = "sha3")]
+use sha3::Sha3_256;
 
 /// A high-level representation of a party in a Hash Commitment Scheme.
 ///
@@ -17,137 +122,2850 @@ use sha2::{Digest, Sha256};
 /// During this last phase, the verifier uses the prover's secret and random number
 /// to forge the expected commitment. If the prover's initial commitment differs from the
 /// expected one, the commitment has not been fulfilled by the prover.
+///
+/// Both phases return a [`HashCommitmentError`] when the secret can not be encoded.
 pub trait HashCommitmentScheme<T: Serialize> {
-    fn commit(&self) -> Result<Vec<u8>>;
-    fn verify(&self, com: &[u8], s: &T, r: &[u8]) -> Result<bool>;
+    /// The length in bytes of the commitments produced by the scheme.
+    const COMMITMENT_LEN: usize;
+
+    /// The bytes of the commitments produced by the scheme, before they are wrapped in a
+    /// [`Commitment`].
+    ///
+    /// Schemes whose commitments have a fixed size use a fixed-size array, so that forging a
+    /// commitment does not require any heap allocation.
+    type Output: AsRef<[u8]>;
+
+    /// Creates the bytes of the commitment used during the commit phase.
+    fn commit_output(&self) -> Result<Self::Output>;
+
+    /// Creates the commitment used during the commit phase.
+    fn commit(&self) -> Result<Commitment> {
+        self.commit_output()
+            .map(|output| Commitment::from(output.as_ref()))
+    }
+
+    /// Returns true if the scheme is perfectly hiding, meaning that a commitment reveals no
+    /// information about the secret even to an attacker with unbounded computing power.
+    ///
+    /// Hash commitments are only computationally hiding: an attacker able to brute-force the
+    /// random number can recover the secret. This is why the default implementation, used by
+    /// every scheme of this crate, returns false.
+    fn is_perfectly_hiding() -> bool {
+        false
+    }
+
+    /// Forges the commitment to the given secret s and random number r, which is the one the
+    /// verifier expects.
+    ///
+    /// Unlike `commit`, the length of the random number is not checked, so that commitments
+    /// forged with short random numbers can still be recomputed.
+    fn recommit(&self, s: &T, r: &[u8]) -> Result<Commitment>;
+
+    /// Recomputes the expected commitment using the prover's secret and random number.
+    /// Then, compares the expected commitment with the prover's one to verify if the commitment
+    /// holds.
+    ///
+    /// The comparison is performed in constant time so that an attacker can not learn how many
+    /// leading bytes of a forged commitment were correct by measuring the verification time.
+    ///
+    /// A commitment whose length differs from `COMMITMENT_LEN` is rejected with a
+    /// [`HashCommitmentError::LengthMismatch`] before any hashing.
+    ///
+    /// The commitment and the random number can be borrowed from any byte container, such as a
+    /// [`Commitment`], a slice, an array or a `Vec<u8>`.
+    fn verify<C, R>(&self, com: &C, s: &T, r: &R) -> Result<bool>
+    where
+        C: AsRef<[u8]> + ?Sized,
+        R: AsRef<[u8]> + ?Sized,
+    {
+        verify_recommitted(self, com.as_ref(), s, r.as_ref())
+    }
+
+    /// Verifies the commitment like `verify`, returning `None` if the secret could not be
+    /// encoded.
+    ///
+    /// This folds the verification into `Option`-based pipelines: `Some(true)` and `Some(false)`
+    /// mean that the opening was checked, and `None` that it could not be. Any other error, such
+    /// as a commitment of the wrong length, means that the commitment does not hold.
+    fn verify_opt<C, R>(&self, com: &C, s: &T, r: &R) -> Option<bool>
+    where
+        C: AsRef<[u8]> + ?Sized,
+        R: AsRef<[u8]> + ?Sized,
+    {
+        match self.verify(com, s, r) {
+            Ok(holds) => Some(holds),
+            Err(e) if e.is_serialization() => None,
+            Err(_) => Some(false),
+        }
+    }
+
+    /// Verifies a commitment using the values bundled in the prover's opening.
+    fn verify_opening<C: AsRef<[u8]> + ?Sized>(
+        &self,
+        com: &C,
+        opening: &Opening<T>,
+    ) -> Result<bool> {
+        self.verify(com, opening.secret(), opening.randomness())
+    }
+
+    /// Verifies that two commitments, forged with the random numbers r1 and r2, both open to
+    /// the same secret s.
+    ///
+    /// This links two commitments by revealing the shared secret once along with both random
+    /// numbers. Both commitments are always verified, so that the time taken does not reveal
+    /// which one does not hold.
+    fn verify_same_secret(
+        &self,
+        com1: &Commitment,
+        com2: &Commitment,
+        s: &T,
+        r1: &[u8],
+        r2: &[u8],
+    ) -> Result<bool> {
+        let first = self.verify(com1, s, r1)?;
+        let second = self.verify(com2, s, r2)?;
+
+        Ok(first & second)
+    }
+
+    /// Returns an [`Equivocation`](protocol::Equivocation) if both openings (s1, r1) and
+    /// (s2, r2) open the commitment while revealing different secrets, or `None` otherwise.
+    ///
+    /// This is impossible for a secure scheme, as it requires a collision of the hash function,
+    /// and proves that the prover committed to two values at once. Both openings are always
+    /// verified, so that the time taken does not reveal which one does not hold.
+    fn detect_equivocation(
+        &self,
+        com: &Commitment,
+        s1: &T,
+        r1: &[u8],
+        s2: &T,
+        r2: &[u8],
+    ) -> Result<Option<protocol::Equivocation<T>>>
+    where
+        T: Clone + PartialEq,
+    {
+        let first = self.verify(com, s1, r1)?;
+        let second = self.verify(com, s2, r2)?;
+
+        if !(first & second) || s1 == s2 {
+            return Ok(None);
+        }
+
+        Ok(Some(protocol::Equivocation::new(
+            Opening::new(s1.clone(), r1.to_vec()),
+            Opening::new(s2.clone(), r2.to_vec()),
+        )))
+    }
+
+    /// Returns the first of the candidate secrets which opens the commitment with the random
+    /// number r, or `None` if none of them does.
+    ///
+    /// Each candidate is verified using `verify`, so the comparison of each recomputed
+    /// commitment is performed in constant time.
+    fn find_matching_secret<'b>(
+        &self,
+        com: &Commitment,
+        candidates: &'b [T],
+        r: &[u8],
+    ) -> Result<Option<&'b T>> {
+        for candidate in candidates {
+            if self.verify(com, candidate, r)? {
+                return Ok(Some(candidate));
+            }
+        }
+
+        Ok(None)
+    }
 }
 
-/// An implementation of the Hash Commitment Scheme using the SHA256 hash function.
+/// A reference to a party is a party as well, so that generic code can commit and verify
+/// through the trait without taking ownership of the party.
+impl<T: Serialize, S: HashCommitmentScheme<T>> HashCommitmentScheme<T> for &S {
+    const COMMITMENT_LEN: usize = S::COMMITMENT_LEN;
+
+    type Output = S::Output;
+
+    fn commit_output(&self) -> Result<Self::Output> {
+        (**self).commit_output()
+    }
+
+    fn commit(&self) -> Result<Commitment> {
+        (**self).commit()
+    }
+
+    fn is_perfectly_hiding() -> bool {
+        S::is_perfectly_hiding()
+    }
+
+    fn recommit(&self, s: &T, r: &[u8]) -> Result<Commitment> {
+        (**self).recommit(s, r)
+    }
+
+    fn verify<C, R>(&self, com: &C, s: &T, r: &R) -> Result<bool>
+    where
+        C: AsRef<[u8]> + ?Sized,
+        R: AsRef<[u8]> + ?Sized,
+    {
+        (**self).verify(com, s, r)
+    }
+}
+
+/// A marker for the commitment schemes whose commitments can be combined additively.
+///
+/// In a homomorphic scheme such as Pedersen commitments, the commitments to s1 and s2 can be
+/// combined into a commitment to s1 + s2, without knowing either secret. None of the schemes of
+/// this crate implement this trait: a hash function destroys any algebraic structure of its
+/// input, so the commitments it produces can only be compared, never combined. Generic code which
+/// relies on combining commitments should be bound by this trait rather than by
+/// [`HashCommitmentScheme`], so that it can not be instantiated with a hash-based scheme.
+///
+/// ```compile_fail
+/// use hashcom_rs::{HomomorphicCommitment, SHA256Commitment};
+///
+/// fn combine<C: HomomorphicCommitment>() {}
+///
+/// combine::<SHA256Commitment<[u8; 4]>>();
+/// ```
+pub trait HomomorphicCommitment {}
+
+/// The minimum length in bytes of the random numbers accepted at commit time, unless configured
+/// otherwise.
+///
+/// A short random number can be brute-forced, which breaks the hiding property of the scheme.
+pub const DEFAULT_MIN_RANDOMNESS_LEN: usize = 16;
+
+/// Checks that a random number is long enough to hide the secret it is committed with.
+///
+/// This is only enforced at commit time, so that commitments forged with shorter random numbers
+/// can still be verified.
+pub(crate) fn check_randomness_len(r: &[u8], min: usize) -> Result<()> {
+    if r.len() < min {
+        return Err(HashCommitmentError::InsufficientRandomness { got: r.len(), min });
+    }
+
+    Ok(())
+}
+
+/// Checks that a commitment has the length of the commitments produced by a scheme.
+///
+/// This lets the verification reject a malformed commitment with a clear error, rather than
+/// reporting that a valid opening does not match it.
+pub(crate) fn check_commitment_len<C: AsRef<[u8]> + ?Sized>(
+    com: &C,
+    expected: usize,
+) -> Result<()> {
+    let actual = com.as_ref().len();
+    if actual != expected {
+        return Err(HashCommitmentError::LengthMismatch { expected, actual });
+    }
+
+    Ok(())
+}
+
+/// Verifies a commitment the way `HashCommitmentScheme::verify` does by default: the length of
+/// the commitment is checked, then the commitment to the secret s and random number r is forged
+/// again and compared with it in constant time.
+fn verify_recommitted<T: Serialize, H: HashCommitmentScheme<T> + ?Sized>(
+    scheme: &H,
+    com: &[u8],
+    s: &T,
+    r: &[u8],
+) -> Result<bool> {
+    check_commitment_len(com, H::COMMITMENT_LEN)?;
+
+    let expected_commitment = scheme.recommit(s, r)?;
+
+    Ok(expected_commitment.as_bytes().ct_eq(com).into())
+}
+
+/// The minimum length in bytes of the truncated commitments.
+///
+/// Below 16 bytes, finding two openings of the same truncated commitment becomes practical.
+pub const MIN_TRUNCATED_LEN: usize = 16;
+
+/// Checks that a digest of the hash function D can be truncated to n bytes.
+fn check_truncated_len<D: Digest>(n: usize) -> Result<()> {
+    if !(MIN_TRUNCATED_LEN..=D::OutputSize::USIZE).contains(&n) {
+        return Err(HashCommitmentError::InvalidTruncation {
+            len: n,
+            min: MIN_TRUNCATED_LEN,
+            max: D::OutputSize::USIZE,
+        });
+    }
+
+    Ok(())
+}
+
+/// The prefix of the domain-separation tags of the indexed commitments, and of the positions of
+/// a vector commitment.
+pub(crate) const INDEX_DOMAIN: &[u8] = b"hashcom-rs/index";
+
+/// The prefix of the domain-separation tags of the round commitments.
+const ROUND_DOMAIN: &[u8] = b"hashcom-rs/round";
+
+/// The prefix of the domain-separation tags of the enum commitments.
+const ENUM_DOMAIN: &[u8] = b"hashcom-rs/enum";
+
+/// The prefix of the domain-separation tags of the timed commitments.
+pub(crate) const TIMED_DOMAIN: &[u8] = b"hashcom-rs/timed";
+
+/// The domain-separation tag of the commitments bound to an identity.
+const IDENTITY_DOMAIN: &[u8] = b"hashcom-rs/identity";
+
+/// Returns the domain-separation tag made of the given prefix, followed by the given value
+/// encoded as a big-endian u64.
+pub(crate) fn tagged_domain(prefix: &[u8], value: u64) -> Vec<u8> {
+    [prefix, &encoding::encode_u64_be(value)].concat()
+}
+
+/// Creates a new hasher, fed with the length-prefixed domain-separation tag if there is one.
+///
+/// If there is associated data, the domain slot is always hashed (empty when there is no domain)
+/// before the length-prefixed associated data, so that associated data can never be mistaken for
+/// a domain.
+pub(crate) fn framing_hasher<D: Digest>(domain: Option<&[u8]>, aad: Option<&[u8]>) -> D {
+    framing_fields(domain, aad).fold(D::new(), |hasher, field| {
+        hasher
+            .chain_update(length_prefix(field))
+            .chain_update(field)
+    })
+}
+
+/// Returns the fields framed before the secret, in order, as hashed by `framing_hasher`.
+fn framing_fields<'f>(
+    domain: Option<&'f [u8]>,
+    aad: Option<&'f [u8]>,
+) -> impl Iterator<Item = &'f [u8]> {
+    let domain = match aad {
+        Some(_) => Some(domain.unwrap_or_default()),
+        None => domain,
+    };
+
+    domain.into_iter().chain(aad)
+}
+
+/// Compares the prover's commitment with an expected commitment in constant time.
+///
+/// This lets a verifier which has already computed or cached the expected commitment check the
+/// prover's one without encoding and hashing the secret again. Commitments of different
+/// lengths never match.
+pub fn verify_bytes(com: &[u8], expected: &[u8]) -> bool {
+    com.ct_eq(expected).into()
+}
+
+/// Forges the commitment to the secret s and random number r using the hash function D, without
+/// creating a party.
+///
+/// This is useful to migrate stored openings from one hash function to another, as the same
+/// opening can be recommitted to under any [`Digest`]. The commitment is the same as the one
+/// forged by a [`DigestCommitment`] using D and the default serializer. As for
+/// [`HashCommitmentScheme::recommit`], the length of the random number is not checked.
+pub fn recommit_with<D: Digest, T: Serialize>(s: &T, r: &[u8]) -> Result<Commitment> {
+    DigestCommitment::<T, D>::new(s, r).recommit(s, r)
+}
+
+/// Forges the commitment to a map using the random number r and the hash function D.
+///
+/// The iteration order of a [`HashMap`](std::collections::HashMap) is not stable, so encoding it
+/// directly would not produce reproducible commitments. The entries are therefore sorted by key
+/// before being encoded, which guarantees that maps holding the same entries always produce the
+/// same commitment, regardless of the order in which they were inserted.
+///
+/// The commitment is the same as the one forged by a [`DigestCommitment`] over a
+/// [`BTreeMap`](alloc::collections::BTreeMap) holding the same entries, which can be used to
+/// verify it.
+///
+/// This function is only available when the `std` feature is enabled.
+#[cfg(feature = "std")]
+pub fn commit_map<D: Digest, K: Ord + Serialize, V: Serialize>(
+    map: &std::collections::HashMap<K, V>,
+    r: &[u8],
+) -> Result<Commitment> {
+    let sorted_map: alloc::collections::BTreeMap<&K, &V> = map.iter().collect();
+
+    DigestCommitment::<_, D>::new(&sorted_map, r).commit()
+}
+
+/// An implementation of the Hash Commitment Scheme which is generic over the hash function.
+///
+/// Any hash function implementing the [`Digest`] trait from the RustCrypto project can be used
+/// to forge the commitments, which means that supporting a new hash function does not require a
+/// dedicated implementation.
 ///
 /// We store the party's secret and random number as references because we don't want to take
 /// ownership over those variables and avoid useless copies (we only perform read operations
 /// with them).
 ///
 /// We use lifetime annotations as we need to store references to existing variables in our
-/// structure, so that an instance of SHA256Commitment can not outlive the references
+/// structure, so that an instance of DigestCommitment can not outlive the references
 /// it holds.
-pub struct SHA256Commitment<'a, T: 'a + Serialize> {
+///
+/// The random number is stored as a [`Cow`] so that it can also be generated and owned by the
+/// party itself (see `with_secure_random`).
+///
+/// The secret is encoded using the serializer S, which defaults to [`BincodeSerializer`].
+///
+/// An optional domain-separation tag can be bound into the commitment (see `with_domain`), so
+/// that the same secret and random number produce different commitments in different protocols.
+/// Per-message public context can be authenticated as well (see `with_associated_data`).
+///
+/// The random number must be at least [`DEFAULT_MIN_RANDOMNESS_LEN`] bytes long to commit, which
+/// can be configured using `with_min_randomness`.
+///
+/// All the options above can also be set from a single entry point using a
+/// [`CommitmentBuilder`].
+///
+/// The hash function and the serializer are only used as type parameters, hence the
+/// [`PhantomData`] markers.
+pub struct DigestCommitment<'a, T: 'a + Serialize, D: Digest, S: Serializer = BincodeSerializer> {
     s: &'a T,
-    r: &'a [u8],
+    r: Cow<'a, [u8]>,
+    domain: Option<&'a [u8]>,
+    aad: Option<&'a [u8]>,
+    min_randomness: usize,
+    _digest: PhantomData<D>,
+    _serializer: PhantomData<S>,
 }
 
-impl<'a, T: 'a + Serialize> SHA256Commitment<'a, T> {
-    /// Creates a new party for the SHA256 Commitment Scheme using its secret and random
-    /// number.
-    pub fn new(s: &'a T, r: &'a [u8]) -> SHA256Commitment<'a, T> {
-        SHA256Commitment { s, r }
+/// The length in bytes of the random numbers generated by the commitment schemes.
+#[cfg(feature = "rand")]
+pub const SECURE_RANDOM_LEN: usize = 32;
+
+/// An implementation of the Hash Commitment Scheme using the SHA256 hash function.
+pub type SHA256Commitment<'a, T> = DigestCommitment<'a, T, Sha256>;
+
+/// An implementation of the Hash Commitment Scheme using the SHA512 hash function.
+///
+/// The commitments produced are 64 bytes long.
+pub type SHA512Commitment<'a, T> = DigestCommitment<'a, T, Sha512>;
+
+/// An implementation of the Hash Commitment Scheme using the SHA3-256 hash function.
+///
+/// This implementation is only available when the `sha3` feature is enabled.
+#[cfg(feature = "sha3")]
+#[allow(non_camel_case_types)]
+pub type SHA3_256Commitment<'a, T> = DigestCommitment<'a, T, Sha3_256>;
+
+/// An implementation of the Hash Commitment Scheme using the Keccak-256 hash function.
+///
+/// This is the pre-standardization variant of SHA3 used by Ethereum, so the commitments produced
+/// match the output of the `keccak256` function available in Solidity for the same preimage.
+///
+/// This implementation is only available when the `keccak` feature is enabled.
+#[cfg(feature = "keccak")]
+pub type Keccak256Commitment<'a, T> = DigestCommitment<'a, T, Keccak256>;
+
+/// An implementation of the Hash Commitment Scheme using the BLAKE2b-512 hash function.
+///
+/// The commitments produced are 64 bytes long, and match the output of libsodium's
+/// `crypto_generichash` with a 64-byte output and no key for the same preimage.
+///
+/// This implementation is only available when the `blake2` feature is enabled.
+#[cfg(feature = "blake2")]
+pub type Blake2bCommitment<'a, T> = DigestCommitment<'a, T, Blake2b512>;
+
+impl<'a, T: 'a + Serialize, D: Digest, S: Serializer> DigestCommitment<'a, T, D, S> {
+    /// Creates a new party for the Commitment Scheme using its secret and random number.
+    ///
+    /// The random number can be borrowed from any byte container, such as a slice, an array or
+    /// a `Vec<u8>`.
+    pub fn new<R: AsRef<[u8]> + ?Sized>(s: &'a T, r: &'a R) -> DigestCommitment<'a, T, D, S> {
+        DigestCommitment {
+            s,
+            r: Cow::Borrowed(r.as_ref()),
+            domain: None,
+            aad: None,
+            min_randomness: DEFAULT_MIN_RANDOMNESS_LEN,
+            _digest: PhantomData,
+            _serializer: PhantomData,
+        }
     }
 
-    /// Forges a commitment given a secret s and a random number r.
+    /// Creates a new party for the Commitment Scheme using its secret, random number and a
+    /// domain-separation tag.
     ///
-    /// We encode the secret to a byte array (which is padded by default), and use it along with
-    /// the random number, given as a byte array, to forge the commitment using the SHA256 hash
-    /// function.
-    fn forge_commitment(&self, s: &T, r: &[u8]) -> Result<Vec<u8>> {
-        let binary_encoded_s = bincode::serialize(s)?;
+    /// The domain is hashed (length-prefixed) before the secret and the random number, which
+    /// prevents a commitment forged for one protocol from being replayed in another one. The
+    /// verifier must use the same domain for the commitment to hold.
+    pub fn with_domain<R: AsRef<[u8]> + ?Sized>(
+        s: &'a T,
+        r: &'a R,
+        domain: &'a [u8],
+    ) -> DigestCommitment<'a, T, D, S> {
+        DigestCommitment {
+            s,
+            r: Cow::Borrowed(r.as_ref()),
+            domain: Some(domain),
+            aad: None,
+            min_randomness: DEFAULT_MIN_RANDOMNESS_LEN,
+            _digest: PhantomData,
+            _serializer: PhantomData,
+        }
+    }
 
-        let hash = Sha256::new()
-            .chain_update(binary_encoded_s.as_slice())
-            .chain_update(r)
-            .finalize();
+    /// Creates a new party for the Commitment Scheme using its secret and a random number whose
+    /// length is fixed at the type level.
+    ///
+    /// The commitment is the same as the one of a party created with `new` from the same bytes.
+    pub fn with_fixed_random<const N: usize>(
+        s: &'a T,
+        r: &'a FixedRandomness<N>,
+    ) -> DigestCommitment<'a, T, D, S> {
+        DigestCommitment::new(s, r.as_bytes())
+    }
 
-        Ok(hash.as_slice().to_vec())
+    /// Creates a new party for the Commitment Scheme using its secret, random number and some
+    /// associated data.
+    ///
+    /// The associated data is public, per-message context (e.g. a session identifier) which is
+    /// authenticated by the commitment without being hidden by it: the verifier must use the
+    /// same associated data for the commitment to hold. Unlike the domain, which is a fixed
+    /// protocol tag, it is expected to change from one commitment to another.
+    pub fn with_associated_data<R: AsRef<[u8]> + ?Sized>(
+        s: &'a T,
+        r: &'a R,
+        aad: &'a [u8],
+    ) -> DigestCommitment<'a, T, D, S> {
+        DigestCommitment {
+            s,
+            r: Cow::Borrowed(r.as_ref()),
+            domain: None,
+            aad: Some(aad),
+            min_randomness: DEFAULT_MIN_RANDOMNESS_LEN,
+            _digest: PhantomData,
+            _serializer: PhantomData,
+        }
     }
-}
 
-impl<'a, T: 'a + Serialize> HashCommitmentScheme<T> for SHA256Commitment<'a, T> {
-    /// Creates the commitment used during the commit phase.
-    fn commit(&self) -> Result<Vec<u8>> {
-        self.forge_commitment(self.s, self.r)
+    /// Creates a new party for the Commitment Scheme using its secret and a random number
+    /// generated from the operating system's cryptographically secure random number generator.
+    ///
+    /// The random number is [`SECURE_RANDOM_LEN`] bytes long and is owned by the party. It can be
+    /// retrieved using the `randomness` method to be revealed during the open phase.
+    ///
+    /// This constructor is only available when the `rand` feature is enabled.
+    #[cfg(feature = "rand")]
+    pub fn with_secure_random(s: &'a T) -> DigestCommitment<'a, T, D, S> {
+        let mut r = vec![0u8; SECURE_RANDOM_LEN];
+        OsRng.fill_bytes(&mut r);
+
+        DigestCommitment {
+            s,
+            r: Cow::Owned(r),
+            domain: None,
+            aad: None,
+            min_randomness: DEFAULT_MIN_RANDOMNESS_LEN,
+            _digest: PhantomData,
+            _serializer: PhantomData,
+        }
     }
 
-    /// Creates the expected commitment using the prover's secret and random number.
-    /// Then, compares the expected commitment with the prover's one to verify if the commitment
-    /// holds.
-    fn verify(&self, com: &[u8], s: &T, r: &[u8]) -> Result<bool> {
-        let expected_commitment = self.forge_commitment(s, r)?;
+    /// Creates a new party for the Commitment Scheme using its secret and a random number
+    /// derived from the given seed.
+    ///
+    /// The random number is [`SECURE_RANDOM_LEN`] bytes long and is owned by the party. Two
+    /// parties created with the same seed use the same random number, which makes this
+    /// constructor suited to reproducible tests and fuzzing only: a guessable seed breaks the
+    /// hiding property of the scheme.
+    ///
+    /// This constructor is only available when the `rand` feature is enabled.
+    #[cfg(feature = "rand")]
+    pub fn with_seeded_random(s: &'a T, seed: u64) -> DigestCommitment<'a, T, D, S> {
+        let mut r = vec![0u8; SECURE_RANDOM_LEN];
+        StdRng::seed_from_u64(seed).fill_bytes(&mut r);
 
-        Ok(expected_commitment == com)
+        DigestCommitment {
+            s,
+            r: Cow::Owned(r),
+            domain: None,
+            aad: None,
+            min_randomness: DEFAULT_MIN_RANDOMNESS_LEN,
+            _digest: PhantomData,
+            _serializer: PhantomData,
+        }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::{HashCommitmentScheme, SHA256Commitment};
-    use hex_literal::hex;
+    /// Creates a new party for the Commitment Scheme using its secret and a random number
+    /// deterministically derived from the secret and a key, in the spirit of RFC 6979.
+    ///
+    /// The random number is `HMAC-SHA256_key(s)`, s being encoded using the serializer S, so
+    /// that the same secret and key always reproduce the same commitment without storing the
+    /// random number. The commitment is only hiding against parties which do not know the key:
+    /// anyone holding it can recompute the random number of any candidate secret, and thus
+    /// brute-force low-entropy secrets.
+    ///
+    /// This constructor is only available when the `hmac` feature is enabled.
+    #[cfg(feature = "hmac")]
+    pub fn with_derived_random(s: &'a T, key: &[u8]) -> Result<DigestCommitment<'a, T, D, S>> {
+        let encoded_s = S::serialize(s)?;
+        let r = <Hmac<Sha256> as Mac>::new_from_slice(key)
+            .expect("HMAC accepts keys of any length")
+            .chain_update(encoded_s)
+            .finalize()
+            .into_bytes();
 
-    #[test]
-    fn it_commits_correctly() {
-        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
-        let r: [u8; 4] = [50, 52, 50, 52]; // 2424 in string format.
+        Ok(DigestCommitment {
+            s,
+            r: Cow::Owned(r.to_vec()),
+            domain: None,
+            aad: None,
+            min_randomness: DEFAULT_MIN_RANDOMNESS_LEN,
+            _digest: PhantomData,
+            _serializer: PhantomData,
+        })
+    }
 
-        let party = SHA256Commitment::new(&s, &r);
-        let commit = party.commit();
+    /// Returns the random number used by the party to forge its commitment.
+    pub fn randomness(&self) -> &[u8] {
+        &self.r
+    }
 
-        assert_eq!(commit.is_ok(), true);
-        assert_eq!(
-            commit.unwrap().as_slice(),
-            hex!("f4417d2878a0e2da0393e604b24a98627fd22506089baa83c165f9ac7b336fe9")
-        )
+    /// Sets the minimum length in bytes of the random number accepted at commit time, which
+    /// defaults to [`DEFAULT_MIN_RANDOMNESS_LEN`].
+    pub fn with_min_randomness(mut self, min_randomness: usize) -> Self {
+        self.min_randomness = min_randomness;
+        self
     }
 
-    /// Here, one party acts as both the prover and the verifier,
-    /// assuming that the verifier is not malicious.
-    #[test]
-    fn it_verifies_valid_commitment() {
-        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
-        let r: [u8; 4] = [50, 52, 50, 52]; // 2424 in string format.
+    /// Reveals the party's secret and random number, to be sent to the verifier during the open
+    /// phase.
+    pub fn open(&self) -> Opening<T>
+    where
+        T: Clone,
+    {
+        Opening::new(self.s.clone(), self.r.to_vec())
+    }
 
-        // Commit phase.
-        let party = SHA256Commitment::new(&s, &r);
-        let commit = party.commit();
+    /// Forges the commitment to the secret s and random number r, bound to the given index.
+    ///
+    /// The index is used as the domain-separation tag, encoded as a big-endian u64 after a fixed
+    /// prefix, so that an opening of one slot can not be reused to open another one, and that an
+    /// indexed commitment never collides with a round, enum or timed one. The commitment is the
+    /// same as the one of the matching position of a
+    /// [`VectorCommitment`](vector::VectorCommitment).
+    ///
+    /// The random number must be at least [`DEFAULT_MIN_RANDOMNESS_LEN`] bytes long.
+    pub fn commit_indexed(index: u64, s: &T, r: &[u8]) -> Result<Commitment> {
+        DigestCommitment::<T, D, S>::with_domain(s, r, &tagged_domain(INDEX_DOMAIN, index)).commit()
+    }
 
-        // Verification phase.
-        let verification = party.verify(&commit.unwrap(), &s, &r);
+    /// Verifies that the secret s and random number r open the commitment com at the given
+    /// index.
+    pub fn verify_indexed<C: AsRef<[u8]> + ?Sized>(
+        com: &C,
+        index: u64,
+        s: &T,
+        r: &[u8],
+    ) -> Result<bool> {
+        DigestCommitment::<T, D, S>::with_domain(s, r, &tagged_domain(INDEX_DOMAIN, index))
+            .verify(com, s, r)
+    }
 
-        assert_eq!(verification.is_ok(), true);
-        assert_eq!(verification.unwrap(), true)
+    /// Forges the commitment to the secret s and random number r, bound to the given round of a
+    /// protocol with repeated rounds.
+    ///
+    /// The round is used as the domain-separation tag, encoded as a big-endian u64 after a fixed
+    /// prefix, so that an opening revealed in one round can not be replayed in another one, and
+    /// that a round commitment never collides with an indexed one. The verifier is responsible
+    /// for checking that the rounds increase.
+    ///
+    /// The random number must be at least [`DEFAULT_MIN_RANDOMNESS_LEN`] bytes long.
+    pub fn commit_round(round: u64, s: &T, r: &[u8]) -> Result<Commitment> {
+        DigestCommitment::<T, D, S>::with_domain(s, r, &tagged_domain(ROUND_DOMAIN, round)).commit()
     }
 
-    /// Here, during the verification phase, we assume that the prover has given an invalid r.
-    #[test]
-    fn it_fails_to_verify_due_to_invalid_random() {
-        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
-        let r: [u8; 4] = [50, 52, 50, 52]; // 2424 in string format.
+    /// Verifies that the secret s and random number r open the commitment com in the given
+    /// round.
+    pub fn verify_round<C: AsRef<[u8]> + ?Sized>(
+        com: &C,
+        round: u64,
+        s: &T,
+        r: &[u8],
+    ) -> Result<bool> {
+        DigestCommitment::<T, D, S>::with_domain(s, r, &tagged_domain(ROUND_DOMAIN, round))
+            .verify(com, s, r)
+    }
 
-        // Commit phase.
-        let party = SHA256Commitment::new(&s, &r);
-        let commit = party.commit();
+    /// Forges the commitment to the secret s and random number r, bound to the given variant
+    /// index of an enum.
+    ///
+    /// The encoding of an enum already holds its discriminant, but the variant index supplied
+    /// by the caller is used as the domain-separation tag as well, after a fixed prefix, so that
+    /// a payload can never be claimed under another variant, whatever the serializer.
+    ///
+    /// The random number must be at least [`DEFAULT_MIN_RANDOMNESS_LEN`] bytes long.
+    pub fn commit_enum(variant: u32, s: &T, r: &[u8]) -> Result<Commitment> {
+        let domain = tagged_domain(ENUM_DOMAIN, u64::from(variant));
 
-        // Verification phase.
-        let fake_r: [u8; 4] = [66, 68, 66, 68];
-        let verification = party.verify(&commit.unwrap(), &s, &fake_r);
+        DigestCommitment::<T, D, S>::with_domain(s, r, &domain).commit()
+    }
 
-        assert_eq!(verification.is_ok(), true);
-        assert_eq!(verification.unwrap(), false)
+    /// Verifies that the secret s and random number r open the commitment com under the given
+    /// variant index.
+    pub fn verify_enum<C: AsRef<[u8]> + ?Sized>(
+        com: &C,
+        variant: u32,
+        s: &T,
+        r: &[u8],
+    ) -> Result<bool> {
+        let domain = tagged_domain(ENUM_DOMAIN, u64::from(variant));
+
+        DigestCommitment::<T, D, S>::with_domain(s, r, &domain).verify(com, s, r)
     }
 
-    /// Here, during the verification phase, we assume that the prover has given an invalid secret.
-    /// This happens when the prover decides to break his initial commitment.
-    #[test]
-    fn it_fails_to_verify_due_to_invalid_secret() {
-        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
-        let r: [u8; 4] = [50, 52, 50, 52]; // 2424 in string format.
+    /// Forges the commitment to the secret s and random number r, bound to the identity of the
+    /// committing party (e.g. its public key or account identifier).
+    ///
+    /// The identity is folded into the preimage as length-prefixed associated data, under a
+    /// fixed domain-separation tag, so that another party can not claim the commitment as its
+    /// own by copying it, and that an identity commitment never collides with a commitment to
+    /// the same associated data.
+    ///
+    /// The random number must be at least [`DEFAULT_MIN_RANDOMNESS_LEN`] bytes long.
+    pub fn commit_bound_to_identity(identity: &[u8], s: &T, r: &[u8]) -> Result<Commitment> {
+        DigestCommitment::<T, D, S>::bound_to_identity(identity, s, r).commit()
+    }
 
-        // Commit phase.
-        let party = SHA256Commitment::new(&s, &r);
-        let commit = party.commit();
+    /// Verifies that the secret s and random number r open the commitment com for the given
+    /// identity.
+    pub fn verify_bound_to_identity<C: AsRef<[u8]> + ?Sized>(
+        com: &C,
+        identity: &[u8],
+        s: &T,
+        r: &[u8],
+    ) -> Result<bool> {
+        DigestCommitment::<T, D, S>::bound_to_identity(identity, s, r).verify(com, s, r)
+    }
 
-        // Verification phase.
-        let fake_s: [u8; 4] = [66, 68, 66, 68];
-        let verification = party.verify(&commit.unwrap(), &fake_s, &r);
+    /// Creates a party whose commitment is bound to the given identity.
+    fn bound_to_identity<'b>(
+        identity: &'b [u8],
+        s: &'b T,
+        r: &'b [u8],
+    ) -> DigestCommitment<'b, T, D, S> {
+        let mut party = DigestCommitment::with_associated_data(s, r, identity);
+        party.domain = Some(IDENTITY_DOMAIN);
+        party
+    }
 
-        assert_eq!(verification.is_ok(), true);
-        assert_eq!(verification.unwrap(), false)
+    /// Forges one commitment per pair of secret and random number, in the same order as the
+    /// given pairs.
+    ///
+    /// When the `rayon` feature is enabled, the commitments are forged in parallel.
+    pub fn batch_commit(items: &[(&'a T, &'a [u8])]) -> Result<Vec<Commitment>>
+    where
+        T: Sync,
+    {
+        #[cfg(feature = "rayon")]
+        let items = items.par_iter();
+        #[cfg(not(feature = "rayon"))]
+        let items = items.iter();
+
+        items
+            .map(|&(s, r)| DigestCommitment::<T, D, S>::new(s, r).commit())
+            .collect()
+    }
+
+    /// Verifies a batch of commitments given the secret and random number revealed for each of
+    /// them, and returns whether each commitment holds, in the same order as the given triples.
+    ///
+    /// A commitment which does not hold does not stop the verification of the others, only a
+    /// secret which can not be encoded does.
+    ///
+    /// When the `rayon` feature is enabled, the commitments are verified in parallel.
+    pub fn batch_verify(triples: &[(&Commitment, &'a T, &'a [u8])]) -> Result<Vec<bool>>
+    where
+        T: Sync,
+    {
+        #[cfg(feature = "rayon")]
+        let triples = triples.par_iter();
+        #[cfg(not(feature = "rayon"))]
+        let triples = triples.iter();
+
+        triples
+            .map(|&(com, s, r)| DigestCommitment::<T, D, S>::new(s, r).verify(com, s, r))
+            .collect()
+    }
+
+    /// Creates a new commitment to the party's secret using the random number new_r, which
+    /// refreshes the commitment without changing the committed value.
+    ///
+    /// The domain and associated data of the party are kept. The new random number must be at
+    /// least as long as the party's minimum. Revealing the secret along with both random numbers
+    /// proves that both commitments bind the same value (see `verify_reblind`).
+    pub fn reblind(&self, new_r: &[u8]) -> Result<Commitment> {
+        check_randomness_len(new_r, self.min_randomness)?;
+
+        self.recommit(self.s, new_r)
+    }
+
+    /// Verifies that the commitment new_com, forged by `reblind`, binds the same secret s as the
+    /// commitment old_com, given the random numbers used for both.
+    ///
+    /// Both commitments are always verified, as done by `verify_same_secret`.
+    pub fn verify_reblind(
+        &self,
+        old_com: &Commitment,
+        new_com: &Commitment,
+        s: &T,
+        old_r: &[u8],
+        new_r: &[u8],
+    ) -> Result<bool> {
+        self.verify_same_secret(old_com, new_com, s, old_r, new_r)
+    }
+
+    /// Verifies the commitment like `verify`, and hints at which of the secret s and random
+    /// number r is wrong when they do not open it.
+    ///
+    /// The hint is found by recomputing the commitment with the party's own secret and random
+    /// number in place of each of the given ones. This compares secrets outside of constant
+    /// time and reveals which part of an opening is wrong, so this method is meant to debug a
+    /// failed verification and is only available in debug builds.
+    #[cfg(debug_assertions)]
+    pub fn verify_explained(&self, com: &Commitment, s: &T, r: &[u8]) -> Result<VerifyOutcome> {
+        if self.verify(com, s, r)? {
+            return Ok(VerifyOutcome::Match);
+        }
+
+        let hint = if self.recommit(self.s, r)? == *com {
+            MismatchHint::Secret
+        } else if self.recommit(s, &self.r)? == *com {
+            MismatchHint::Randomness
+        } else {
+            MismatchHint::Unknown
+        };
+
+        Ok(VerifyOutcome::Mismatch(hint))
+    }
+
+    /// Creates the commitment used during the commit phase, along with a self-describing opening
+    /// blob to be stored until the open phase.
+    ///
+    /// The blob holds the name of the hash function, the encoded secret, the random number, and
+    /// the domain and associated data of the party. It can be verified using [`verify_blob`]
+    /// without decoding the secret, given the domain and associated data expected by the
+    /// verifier.
+    pub fn commit_with_opening(&self) -> Result<(Commitment, Vec<u8>)>
+    where
+        D: NamedDigest,
+    {
+        let commit = self.commit()?;
+        let encoded_s = S::serialize(self.s)?;
+        let blob = blob::encode_opening(D::NAME, &encoded_s, &self.r, self.domain, self.aad);
+
+        Ok((commit, blob))
+    }
+
+    /// Creates the envelope sent to the verifier during the commit phase, holding the
+    /// commitment and the name of the hash function, but not the secret.
+    ///
+    /// This method is only available when the `serde-derive` feature is enabled.
+    #[cfg(feature = "serde-derive")]
+    pub fn commit_envelope(&self) -> Result<CommitEnvelope>
+    where
+        D: NamedDigest,
+    {
+        Ok(CommitEnvelope::new(self.commit()?, D::NAME))
+    }
+
+    /// Creates the envelope sent to the verifier during the open phase, holding the encoded
+    /// secret and the random number.
+    ///
+    /// This method is only available when the `serde-derive` feature is enabled.
+    #[cfg(feature = "serde-derive")]
+    pub fn open_envelope(&self) -> Result<OpenEnvelope> {
+        Ok(OpenEnvelope::new(S::serialize(self.s)?, self.r.to_vec()))
+    }
+
+    /// Verifies that the opening sent in open_env opens the commitment sent in commit_env.
+    ///
+    /// The commitment is forged again from the encoded secret and the random number, using the
+    /// domain and associated data of this party, then compared with the prover's one in constant
+    /// time. The secret of this party is not used, so a verifier can create it with any value.
+    ///
+    /// Returns a [`HashCommitmentError::SchemeMismatch`] if the commitment was forged using
+    /// another hash function than D, and a [`HashCommitmentError::LengthMismatch`] if the
+    /// length of the commitment, or the one announced in the envelope, is not `COMMITMENT_LEN`.
+    ///
+    /// This method is only available when the `serde-derive` feature is enabled.
+    #[cfg(feature = "serde-derive")]
+    pub fn verify_envelope(
+        &self,
+        commit_env: &CommitEnvelope,
+        open_env: &OpenEnvelope,
+    ) -> Result<bool>
+    where
+        D: NamedDigest,
+    {
+        if commit_env.scheme_id() != D::NAME {
+            return Err(HashCommitmentError::SchemeMismatch);
+        }
+        if commit_env.commitment_len() != Self::COMMITMENT_LEN {
+            return Err(HashCommitmentError::LengthMismatch {
+                expected: Self::COMMITMENT_LEN,
+                actual: commit_env.commitment_len(),
+            });
+        }
+        check_commitment_len(commit_env.commitment(), Self::COMMITMENT_LEN)?;
+
+        let expected_commitment = blob::forge_encoded::<D>(
+            open_env.secret_bytes(),
+            open_env.randomness(),
+            self.domain,
+            self.aad,
+        );
+
+        Ok(expected_commitment
+            .ct_eq(commit_env.commitment().as_bytes())
+            .into())
+    }
+
+    /// Lazily forges a commitment for each secret of the given iterator, along with the random
+    /// number used to forge it, in the same order as the given secrets.
+    ///
+    /// A fresh random number of [`SECURE_RANDOM_LEN`] bytes is generated for each secret from
+    /// the operating system's cryptographically secure random number generator, and must be
+    /// revealed along with the secret during the open phase.
+    ///
+    /// This function is only available when the `rand` feature is enabled.
+    #[cfg(feature = "rand")]
+    pub fn commit_stream<I: Iterator<Item = T>>(
+        items: I,
+    ) -> impl Iterator<Item = Result<(Commitment, Vec<u8>)>> {
+        items.map(|s| {
+            let party = DigestCommitment::<T, D, S>::with_secure_random(&s);
+            let commit = party.commit()?;
+
+            Ok((commit, party.r.into_owned()))
+        })
+    }
+
+    /// Forges one commitment to the same secret s for each of the given random numbers, in the
+    /// same order as the random numbers.
+    ///
+    /// The secret is encoded once, and the encoded buffer is hashed along with each random
+    /// number, so the commitments are the same as the ones forged by a party for each random
+    /// number, without encoding the secret again.
+    ///
+    /// Each random number must be at least [`DEFAULT_MIN_RANDOMNESS_LEN`] bytes long.
+    pub fn commit_many_randoms(s: &T, randoms: &[&[u8]]) -> Result<Vec<Commitment>> {
+        let encoded_s = S::serialize(s)?;
+
+        randoms
+            .iter()
+            .map(|r| {
+                check_randomness_len(r, DEFAULT_MIN_RANDOMNESS_LEN)?;
+
+                let hash = blob::forge_encoded::<D>(&encoded_s, r, None, None);
+
+                Ok(Commitment::from(hash.to_vec()))
+            })
+            .collect()
+    }
+
+    /// Forges a single commitment binding several secrets with one random number r.
+    ///
+    /// The number of secrets is hashed first, then each encoded secret prefixed with its
+    /// length, in order, and finally the length-prefixed random number. Both the order and the
+    /// boundaries of the secrets are therefore bound, so that `[a, b]` and `[ab]` produce
+    /// different commitments.
+    ///
+    /// The random number must be at least [`DEFAULT_MIN_RANDOMNESS_LEN`] bytes long.
+    pub fn commit_multi(secrets: &[&T], r: &[u8]) -> Result<Commitment> {
+        check_randomness_len(r, DEFAULT_MIN_RANDOMNESS_LEN)?;
+
+        Self::forge_multi_commitment(secrets, r).map(Commitment::from)
+    }
+
+    /// Verifies a commitment forged using `commit_multi`, given the secrets and the random
+    /// number revealed by the prover, in the same order as they were committed to.
+    ///
+    /// A commitment whose length differs from `COMMITMENT_LEN` is rejected with a
+    /// [`HashCommitmentError::LengthMismatch`] before any hashing.
+    pub fn verify_multi(com: &Commitment, secrets: &[&T], r: &[u8]) -> Result<bool> {
+        check_commitment_len(com, D::OutputSize::USIZE)?;
+
+        let expected_commitment = Self::forge_multi_commitment(secrets, r)?;
+
+        Ok(expected_commitment.ct_eq(com.as_bytes()).into())
+    }
+
+    /// Creates the commitment used during the commit phase, truncated to its first n bytes.
+    ///
+    /// Truncating the digest reduces the binding property of the scheme to about n * 4 bits of
+    /// security against collisions, so n must be at least [`MIN_TRUNCATED_LEN`] and at most the
+    /// output size of the hash function, otherwise a [`HashCommitmentError::InvalidTruncation`]
+    /// is returned. The commitment must be verified using `verify_truncated` with the same n.
+    pub fn commit_truncated(&self, n: usize) -> Result<Commitment> {
+        check_truncated_len::<D>(n)?;
+
+        let output = self.commit_output()?;
+
+        Ok(Commitment::from(&output[..n]))
+    }
+
+    /// Verifies a commitment created by `commit_truncated` with the same n, comparing it in
+    /// constant time with the first n bytes of the expected commitment.
+    ///
+    /// A commitment which is not n bytes long is rejected with a
+    /// [`HashCommitmentError::LengthMismatch`] before any hashing.
+    pub fn verify_truncated(&self, com: &Commitment, s: &T, r: &[u8], n: usize) -> Result<bool> {
+        check_truncated_len::<D>(n)?;
+        check_commitment_len(com, n)?;
+
+        let expected_commitment = self.hash_preimage(s, r)?.finalize();
+
+        Ok(expected_commitment[..n].ct_eq(com.as_bytes()).into())
+    }
+
+    /// Creates the commitment used during the commit phase as a [`GenericArray`], without
+    /// allocating it.
+    ///
+    /// This is the digest type used across the RustCrypto ecosystem, so the commitment can be
+    /// passed wherever one is expected. The bytes are the same as the ones of the commitment
+    /// returned by `commit`.
+    ///
+    /// [`GenericArray`]: sha2::digest::generic_array::GenericArray
+    pub fn commit_generic(
+        &self,
+    ) -> Result<sha2::digest::generic_array::GenericArray<u8, D::OutputSize>> {
+        self.commit_output()
+    }
+
+    /// Writes the commitment used during the commit phase into the given buffer, without
+    /// allocating it.
+    ///
+    /// The buffer must be exactly `COMMITMENT_LEN` bytes long, otherwise a
+    /// [`HashCommitmentError::LengthMismatch`] is returned. The bytes written are the same as
+    /// the ones of the commitment returned by `commit`.
+    pub fn commit_into(&self, out: &mut [u8]) -> Result<()> {
+        check_randomness_len(&self.r, self.min_randomness)?;
+
+        if out.len() != D::OutputSize::USIZE {
+            return Err(HashCommitmentError::LengthMismatch {
+                expected: D::OutputSize::USIZE,
+                actual: out.len(),
+            });
+        }
+
+        self.hash_preimage(self.s, &self.r)?
+            .finalize_into(sha2::digest::Output::<D>::from_mut_slice(out));
+
+        Ok(())
+    }
+
+    /// Creates the commitment used during the commit phase on tokio's blocking thread pool, so
+    /// that hashing a large secret does not block the executor.
+    ///
+    /// The secret, the random number and the domain are copied to be moved to the blocking
+    /// task, hence the `Clone`, `Send` and `'static` bounds. The commitment is the same as the
+    /// one returned by `commit`.
+    ///
+    /// Returns a [`HashCommitmentError::Join`] if the blocking task panicked or was cancelled.
+    ///
+    /// This method is only available when the `tokio` feature is enabled, and must be called
+    /// from within a tokio runtime.
+    #[cfg(feature = "tokio")]
+    pub async fn commit_async(&self) -> Result<Commitment>
+    where
+        T: Clone + Send + 'static,
+        D: 'static,
+        S: 'static,
+    {
+        let s = self.s.clone();
+        let r = self.r.to_vec();
+        let domain = self.domain.map(<[u8]>::to_vec);
+        let aad = self.aad.map(<[u8]>::to_vec);
+        let min_randomness = self.min_randomness;
+
+        tokio::task::spawn_blocking(move || {
+            DigestCommitment::<T, D, S> {
+                s: &s,
+                r: Cow::Borrowed(&r),
+                domain: domain.as_deref(),
+                aad: aad.as_deref(),
+                min_randomness,
+                _digest: PhantomData,
+                _serializer: PhantomData,
+            }
+            .commit()
+        })
+        .await?
+    }
+
+    /// Creates the commitment used during the commit phase, encoding the secret into the given
+    /// scratch buffer rather than into a newly allocated one.
+    ///
+    /// The buffer is cleared before being used, so it can be reused across commitments to
+    /// secrets of similar sizes without allocating once it is large enough. It holds the encoded
+    /// secret when this method returns. The commitment is the same as the one returned by
+    /// `commit`.
+    pub fn commit_with_buffer(&self, scratch: &mut Vec<u8>) -> Result<Commitment> {
+        check_randomness_len(&self.r, self.min_randomness)?;
+
+        scratch.clear();
+        S::serialize_into(self.s, scratch)?;
+
+        let hash = self
+            .domain_hasher()
+            .chain_update(length_prefix(scratch))
+            .chain_update(scratch.as_slice())
+            .chain_update(length_prefix(&self.r))
+            .chain_update(&self.r)
+            .finalize();
+
+        Ok(Commitment::from(hash.to_vec()))
+    }
+
+    /// Returns the length in bytes of the party's secret once encoded by the serializer S.
+    ///
+    /// The bincode serializer measures the encoding without buffering it, so this can be used to
+    /// size a buffer before encoding a large secret into it using the serializer's
+    /// `serialize_into`.
+    pub fn serialized_size(&self) -> Result<u64> {
+        Ok(S::serialized_len(self.s)? as u64)
+    }
+
+    /// Returns the exact bytes hashed to forge the party's commitment.
+    ///
+    /// This is meant to debug a commitment which another implementation does not reproduce, by
+    /// comparing the preimages built by both implementations.
+    pub fn preimage(&self) -> Result<Vec<u8>> {
+        self.preimage_of(self.s, &self.r)
+    }
+
+    /// Returns the exact bytes hashed to forge the commitment to the secret s and random number
+    /// r, under the party's domain and associated data.
+    ///
+    /// The preimage holds the length-prefixed domain and associated data if the party has them,
+    /// then the length-prefixed encoded secret, and finally the length-prefixed random number.
+    /// This is the preimage hashed by any serializer which frames the secret, as checked by
+    /// `check_framing_unambiguous`.
+    pub fn preimage_of(&self, s: &T, r: &[u8]) -> Result<Vec<u8>> {
+        let builder = framing_fields(self.domain, self.aad)
+            .fold(encoding::PreimageBuilder::new(), |builder, field| {
+                builder.field(field)
+            });
+
+        Ok(builder.field(&S::serialize(s)?).field(r).finish())
+    }
+
+    /// Checks that the preimage hashed by the party is framed with length prefixes.
+    ///
+    /// The digest fed by the serializer S is compared with the one of the framed preimage built
+    /// from the encoded secret, so this only fails for a serializer whose `update_digest` drops
+    /// the length prefix, which would let the secret and the random number alias each other.
+    /// It also fails if the secret can not be encoded.
+    ///
+    /// This check runs on each commit in debug builds, which panic if it fails.
+    pub fn check_framing_unambiguous(&self) -> bool {
+        let (Ok(hasher), Ok(encoded_s)) =
+            (self.hash_preimage(self.s, &self.r), S::serialize(self.s))
+        else {
+            return false;
+        };
+
+        let framed = self
+            .domain_hasher()
+            .chain_update(length_prefix(&encoded_s))
+            .chain_update(&encoded_s)
+            .chain_update(length_prefix(&self.r))
+            .chain_update(&self.r);
+
+        hasher.finalize() == framed.finalize()
+    }
+
+    /// Forges the commitment to the party's secret and random number, once the random number
+    /// has been checked.
+    fn checked_output(&self) -> Result<sha2::digest::Output<D>> {
+        check_randomness_len(&self.r, self.min_randomness)?;
+
+        let hasher = self.hash_preimage(self.s, &self.r)?;
+        debug_assert!(
+            self.check_framing_unambiguous(),
+            "the serializer does not frame the secret with a length prefix"
+        );
+
+        Ok(hasher.finalize())
+    }
+
+    /// Forges a commitment given a secret s and a random number r.
+    ///
+    /// The encoded secret is streamed to the hasher by the serializers able to do so, so it is
+    /// only buffered by the other ones.
+    fn forge_commitment(&self, s: &T, r: &[u8]) -> Result<Vec<u8>> {
+        Ok(self.hash_preimage(s, r)?.finalize().to_vec())
+    }
+
+    /// Forges a commitment to several secrets using a single random number r.
+    fn forge_multi_commitment(secrets: &[&T], r: &[u8]) -> Result<Vec<u8>> {
+        let mut hasher = D::new().chain_update(encode_len(secrets.len()));
+        for s in secrets {
+            S::update_digest(*s, &mut hasher)?;
+        }
+
+        let hash = hasher
+            .chain_update(length_prefix(r))
+            .chain_update(r)
+            .finalize();
+
+        Ok(hash.to_vec())
+    }
+
+    /// Feeds the preimage of the commitment to a new hasher.
+    ///
+    /// We encode the secret to a byte array using the serializer S, and use it along with
+    /// the random number, given as a byte array, to forge the commitment using the hash
+    /// function D. Both fields are prefixed with their length before being hashed. The
+    /// serializer streams the encoded secret to the hasher when it is able to.
+    ///
+    /// If the party has a domain-separation tag, it is hashed first, prefixed with its length
+    /// as well.
+    fn hash_preimage(&self, s: &T, r: &[u8]) -> Result<D> {
+        let mut hasher = self.domain_hasher();
+
+        S::update_digest(s, &mut hasher)?;
+
+        Ok(hasher.chain_update(length_prefix(r)).chain_update(r))
+    }
+
+    /// Creates a new hasher, fed with the length-prefixed domain-separation tag and associated
+    /// data if the party has them.
+    fn domain_hasher(&self) -> D {
+        framing_hasher(self.domain, self.aad)
+    }
+}
+
+#[cfg(feature = "json")]
+impl<'a, D: Digest> DigestCommitment<'a, serde_json::Value, D> {
+    /// Forges the commitment to the JSON value v and random number r.
+    ///
+    /// The value is encoded as JSON with the keys of each object sorted, so that the same
+    /// document always produces the same commitment whatever the order of its keys. This suits
+    /// schema-less data with no concrete type to encode, and the commitment is the same as the
+    /// one forged from v under the [`JsonSerializer`](serializer::JsonSerializer) when its keys
+    /// are sorted.
+    ///
+    /// The random number must be at least [`DEFAULT_MIN_RANDOMNESS_LEN`] bytes long.
+    ///
+    /// This method is only available when the `json` feature is enabled.
+    pub fn commit_json_value(v: &serde_json::Value, r: &[u8]) -> Result<Commitment> {
+        check_randomness_len(r, DEFAULT_MIN_RANDOMNESS_LEN)?;
+
+        let hash = blob::forge_encoded::<D>(&serializer::canonical_json(v)?, r, None, None);
+
+        Ok(Commitment::from(hash.to_vec()))
+    }
+
+    /// Verifies that the JSON value v and random number r open the commitment com, whatever
+    /// the order of the keys of v.
+    ///
+    /// This method is only available when the `json` feature is enabled.
+    pub fn verify_json_value(com: &Commitment, v: &serde_json::Value, r: &[u8]) -> Result<bool> {
+        check_commitment_len(com, Self::COMMITMENT_LEN)?;
+
+        let expected_commitment =
+            blob::forge_encoded::<D>(&serializer::canonical_json(v)?, r, None, None);
+
+        Ok(expected_commitment.ct_eq(com.as_bytes()).into())
+    }
+}
+
+/// The secret and the random number are redacted, so that a party can be logged without
+/// breaking the hiding property of its commitment. Only the length of the random number is
+/// shown.
+impl<'a, T: 'a + Serialize, D: Digest, S: Serializer> fmt::Debug for DigestCommitment<'a, T, D, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DigestCommitment")
+            .field("secret", &format_args!("<redacted>"))
+            .field("r_len", &self.r.len())
+            .field("domain", &self.domain.map(base16ct::lower::encode_string))
+            .field("min_randomness", &self.min_randomness)
+            .finish()
+    }
+}
+
+impl<'a, T: 'a + Serialize, D: Digest, S: Serializer> HashCommitmentScheme<T>
+    for DigestCommitment<'a, T, D, S>
+{
+    const COMMITMENT_LEN: usize = D::OutputSize::USIZE;
+
+    type Output = sha2::digest::Output<D>;
+
+    /// Creates the bytes of the commitment used during the commit phase, as the output array of
+    /// the hash function.
+    ///
+    /// Returns a [`HashCommitmentError::InsufficientRandomness`] if the random number is too
+    /// short to hide the secret.
+    ///
+    /// When the `tracing` feature is enabled, this runs within a `commit` span recording the
+    /// lengths of the encoded secret and of the random number, but never their bytes.
+    fn commit_output(&self) -> Result<Self::Output> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!(
+            "commit",
+            secret_len = S::serialized_len(self.s).ok(),
+            r_len = self.r.len()
+        )
+        .entered();
+
+        let output = self.checked_output();
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(committed = output.is_ok(), "forged commitment");
+
+        output
+    }
+
+    fn recommit(&self, s: &T, r: &[u8]) -> Result<Commitment> {
+        self.forge_commitment(s, r).map(Commitment::from)
+    }
+
+    /// Verifies the commitment the same way as the default implementation, within a `verify`
+    /// span recording the lengths of the encoded secret and of the random number, and whether
+    /// the commitment matched, but never their bytes.
+    #[cfg(feature = "tracing")]
+    fn verify<C, R>(&self, com: &C, s: &T, r: &R) -> Result<bool>
+    where
+        C: AsRef<[u8]> + ?Sized,
+        R: AsRef<[u8]> + ?Sized,
+    {
+        let _span = tracing::debug_span!(
+            "verify",
+            secret_len = S::serialized_len(s).ok(),
+            r_len = r.as_ref().len()
+        )
+        .entered();
+
+        let matched = verify_recommitted(self, com.as_ref(), s, r.as_ref())?;
+
+        tracing::debug!(matched, "verified commitment");
+
+        Ok(matched)
+    }
+}
+
+/// An implementation of the Hash Commitment Scheme using the BLAKE3 hash function.
+///
+/// BLAKE3 is much faster than the SHA2 family on large inputs, which makes it a good fit when
+/// committing to big secrets. The commitments produced are 32 bytes long.
+///
+/// The `blake3` crate exposes its own incremental hasher rather than the [`Digest`] trait, which
+/// is why this implementation does not rely on [`DigestCommitment`].
+///
+/// This implementation is only available when the `blake3` feature is enabled.
+#[cfg(feature = "blake3")]
+pub struct Blake3Commitment<'a, T: 'a + Serialize> {
+    s: &'a T,
+    r: &'a [u8],
+    min_randomness: usize,
+}
+
+#[cfg(feature = "blake3")]
+impl<'a, T: 'a + Serialize> Blake3Commitment<'a, T> {
+    /// Creates a new party for the BLAKE3 Commitment Scheme using its secret and random
+    /// number.
+    ///
+    /// The random number can be borrowed from any byte container, such as a slice, an array or
+    /// a `Vec<u8>`.
+    pub fn new<R: AsRef<[u8]> + ?Sized>(s: &'a T, r: &'a R) -> Blake3Commitment<'a, T> {
+        Blake3Commitment {
+            s,
+            r: r.as_ref(),
+            min_randomness: DEFAULT_MIN_RANDOMNESS_LEN,
+        }
+    }
+
+    /// Sets the minimum length in bytes of the random number accepted at commit time, which
+    /// defaults to [`DEFAULT_MIN_RANDOMNESS_LEN`].
+    pub fn with_min_randomness(mut self, min_randomness: usize) -> Self {
+        self.min_randomness = min_randomness;
+        self
+    }
+
+    /// Reveals the party's secret and random number, to be sent to the verifier during the open
+    /// phase.
+    pub fn open(&self) -> Opening<T>
+    where
+        T: Clone,
+    {
+        Opening::new(self.s.clone(), self.r.to_vec())
+    }
+
+    /// Forges a commitment given a secret s and a random number r.
+    ///
+    /// The hasher is updated with the length-prefixed encoded secret first, then with the
+    /// length-prefixed random number, the same way [`DigestCommitment`] does.
+    fn forge_commitment(&self, s: &T, r: &[u8]) -> Result<blake3::Hash> {
+        let binary_encoded_s = BincodeSerializer::serialize(s)?;
+
+        let hash = blake3::Hasher::new()
+            .update(&length_prefix(&binary_encoded_s))
+            .update(binary_encoded_s.as_slice())
+            .update(&length_prefix(r))
+            .update(r)
+            .finalize();
+
+        Ok(hash)
+    }
+}
+
+#[cfg(feature = "blake3")]
+impl<'a, T: 'a + Serialize> HashCommitmentScheme<T> for Blake3Commitment<'a, T> {
+    const COMMITMENT_LEN: usize = blake3::OUT_LEN;
+
+    type Output = [u8; blake3::OUT_LEN];
+
+    /// Creates the bytes of the commitment used during the commit phase.
+    ///
+    /// Returns a [`HashCommitmentError::InsufficientRandomness`] if the random number is too
+    /// short to hide the secret.
+    fn commit_output(&self) -> Result<Self::Output> {
+        check_randomness_len(self.r, self.min_randomness)?;
+
+        self.forge_commitment(self.s, self.r)
+            .map(|hash| *hash.as_bytes())
+    }
+
+    fn recommit(&self, s: &T, r: &[u8]) -> Result<Commitment> {
+        self.forge_commitment(s, r)
+            .map(|hash| Commitment::from(hash.as_bytes().as_slice()))
+    }
+}
+
+// Some tests compare the outcome of a verification with a boolean literal.
+#[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
+mod tests {
+    use super::{
+        Commitment, DigestCommitment, HashCommitmentError, HashCommitmentScheme, SHA256Commitment,
+        SHA512Commitment,
+    };
+    use hex_literal::hex;
+    use sha2::Sha256;
+
+    #[test]
+    fn it_commits_correctly() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let party = SHA256Commitment::new(&s, &r);
+        let commit = party.commit();
+
+        assert_eq!(commit.is_ok(), true);
+        assert_eq!(
+            commit.unwrap().as_bytes(),
+            hex!("4385e32a44ae244b0a65234c7fbbe8e11d51f1ee874b74781a6476960229b546")
+        )
+    }
+
+    /// Here, one party acts as both the prover and the verifier,
+    /// assuming that the verifier is not malicious.
+    #[test]
+    fn it_verifies_valid_commitment() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        // Commit phase.
+        let party = SHA256Commitment::new(&s, &r);
+        let commit = party.commit();
+
+        // Verification phase.
+        let verification = party.verify(&commit.unwrap(), &s, &r);
+
+        assert_eq!(verification.is_ok(), true);
+        assert_eq!(verification.unwrap(), true)
+    }
+
+    /// Here, during the verification phase, we assume that the prover has given an invalid r.
+    #[test]
+    fn it_fails_to_verify_due_to_invalid_random() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        // Commit phase.
+        let party = SHA256Commitment::new(&s, &r);
+        let commit = party.commit();
+
+        // Verification phase.
+        let fake_r: [u8; 4] = [66, 68, 66, 68];
+        let verification = party.verify(&commit.unwrap(), &s, &fake_r);
+
+        assert_eq!(verification.is_ok(), true);
+        assert_eq!(verification.unwrap(), false)
+    }
+
+    /// Here, during the verification phase, we assume that the prover has given an invalid secret.
+    /// This happens when the prover decides to break his initial commitment.
+    #[test]
+    fn it_fails_to_verify_due_to_invalid_secret() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        // Commit phase.
+        let party = SHA256Commitment::new(&s, &r);
+        let commit = party.commit();
+
+        // Verification phase.
+        let fake_s: [u8; 4] = [66, 68, 66, 68];
+        let verification = party.verify(&commit.unwrap(), &fake_s, &r);
+
+        assert_eq!(verification.is_ok(), true);
+        assert_eq!(verification.unwrap(), false)
+    }
+
+    /// The SHA256Commitment alias must keep producing the same commitments as the generic
+    /// implementation instantiated with the SHA256 hash function.
+    #[test]
+    fn it_commits_identically_through_the_alias() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let alias_party = SHA256Commitment::new(&s, &r);
+        let generic_party = DigestCommitment::<_, Sha256>::new(&s, &r);
+
+        let alias_commit = alias_party.commit().unwrap();
+        let generic_commit = generic_party.commit().unwrap();
+
+        assert_eq!(alias_commit, generic_commit);
+        assert_eq!(
+            generic_commit.as_bytes(),
+            hex!("4385e32a44ae244b0a65234c7fbbe8e11d51f1ee874b74781a6476960229b546")
+        )
+    }
+
+    #[test]
+    fn it_commits_correctly_with_sha512() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let party = SHA512Commitment::new(&s, &r);
+        let commit = party.commit();
+
+        assert!(commit.is_ok());
+        assert_eq!(
+            commit.unwrap().as_bytes(),
+            hex!(
+                "70dd89efd0ca9413cea2af18599352a49ac944872a785a569f3f9bd59db893eb"
+                "692953b44578ec7fe05037df296eb22bb5696f51d02fe5212159e3b97395fbf2"
+            )
+        )
+    }
+
+    /// Here, one party acts as both the prover and the verifier,
+    /// assuming that the verifier is not malicious.
+    #[test]
+    fn it_verifies_valid_sha512_commitment() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        // Commit phase.
+        let party = SHA512Commitment::new(&s, &r);
+        let commit = party.commit().unwrap();
+
+        assert_eq!(commit.len(), 64);
+
+        // Verification phase.
+        let verification = party.verify(&commit, &s, &r);
+
+        assert!(verification.is_ok());
+        assert!(verification.unwrap())
+    }
+
+    #[cfg(feature = "sha3")]
+    #[test]
+    fn it_commits_correctly_with_sha3_256() {
+        use super::SHA3_256Commitment;
+
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let party = SHA3_256Commitment::new(&s, &r);
+        let commit = party.commit();
+
+        assert!(commit.is_ok());
+        assert_eq!(
+            commit.unwrap().as_bytes(),
+            hex!("0511def540e79440bba3bd3b901330a4860b774de34afd3c9894fa13f36e99a1")
+        )
+    }
+
+    /// Here, the expected commitment is computed using an independent implementation of the
+    /// Keccak-256 hash function, to make sure both produce the same digests byte for byte.
+    #[cfg(feature = "keccak")]
+    #[test]
+    fn it_commits_like_an_independent_keccak256_implementation() {
+        use super::serializer::{BincodeSerializer, Serializer};
+        use super::Keccak256Commitment;
+        use tiny_keccak::{Hasher, Keccak};
+
+        let s: &[u8] = &[52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let party = Keccak256Commitment::new(&s, &r);
+        let commit = party.commit();
+
+        let binary_encoded_s = BincodeSerializer::serialize(&s).unwrap();
+        let mut expected = [0u8; 32];
+        let mut keccak = Keccak::v256();
+        keccak.update(&(binary_encoded_s.len() as u64).to_be_bytes());
+        keccak.update(&binary_encoded_s);
+        keccak.update(&(r.len() as u64).to_be_bytes());
+        keccak.update(&r);
+        keccak.finalize(&mut expected);
+
+        assert!(commit.is_ok());
+        assert_eq!(commit.unwrap().as_bytes(), expected)
+    }
+
+    /// The expected commitment is computed using Python's `hashlib.blake2b` over the
+    /// length-prefixed preimage.
+    #[cfg(feature = "blake2")]
+    #[test]
+    fn it_commits_correctly_with_blake2b() {
+        use super::Blake2bCommitment;
+
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let party = Blake2bCommitment::new(&s, &r);
+        let commit = party.commit();
+
+        assert!(commit.is_ok());
+        assert_eq!(
+            commit.unwrap().as_bytes(),
+            hex!(
+                "53c9f694192b2c050ede3ab88a9c0f5bbfe569a4446a55b0e1778685afda5eed"
+                "013f21ec9ccea371603275fd1ba5ca18ea6e2833eccd025197c6399b3b87c633"
+            )
+        )
+    }
+
+    /// Here, one party acts as both the prover and the verifier,
+    /// assuming that the verifier is not malicious.
+    #[cfg(feature = "blake2")]
+    #[test]
+    fn it_verifies_valid_blake2b_commitment() {
+        use super::Blake2bCommitment;
+
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+        let fake: [u8; 4] = [66, 68, 66, 68];
+
+        // Commit phase.
+        let party = Blake2bCommitment::new(&s, &r);
+        let commit = party.commit().unwrap();
+
+        assert_eq!(commit.len(), Blake2bCommitment::<[u8; 4]>::COMMITMENT_LEN);
+        assert_eq!(commit.len(), 64);
+
+        // Verification phase.
+        assert!(party.verify(&commit, &s, &r).unwrap());
+        assert!(!party.verify(&commit, &fake, &r).unwrap())
+    }
+
+    /// Here, one party acts as both the prover and the verifier,
+    /// assuming that the verifier is not malicious.
+    #[cfg(feature = "blake3")]
+    #[test]
+    fn it_verifies_valid_blake3_commitment() {
+        use super::Blake3Commitment;
+
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        // Commit phase.
+        let party = Blake3Commitment::new(&s, &r);
+        let commit = party.commit().unwrap();
+
+        assert_eq!(commit.len(), 32);
+        assert_eq!(
+            commit.as_bytes(),
+            blake3::hash(b"\0\0\0\0\0\0\0\x044242\0\0\0\0\0\0\0\x102424242424242424").as_bytes()
+        );
+
+        // Verification phase.
+        let verification = party.verify(&commit, &s, &r);
+        let fake_r: [u8; 4] = [66, 68, 66, 68];
+        let fake_verification = party.verify(&commit, &s, &fake_r);
+
+        assert!(verification.unwrap());
+        assert!(!fake_verification.unwrap())
+    }
+
+    /// Here, the constant-time comparison must still accept the valid opening and reject both
+    /// an invalid random number and an invalid secret.
+    #[test]
+    fn it_verifies_in_constant_time() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+        let fake: [u8; 4] = [66, 68, 66, 68];
+
+        // Commit phase.
+        let party = SHA256Commitment::new(&s, &r);
+        let commit = party.commit().unwrap();
+
+        // Verification phase.
+        assert!(party.verify(&commit, &s, &r).unwrap());
+        assert!(!party.verify(&commit, &s, &fake).unwrap());
+        assert!(!party.verify(&commit, &fake, &r).unwrap())
+    }
+
+    /// Here, the prover tries to open its commitment with a different pair of secret and random
+    /// number whose concatenation is the same as the original one. Without length prefixes,
+    /// both pairs would hash to the same commitment.
+    #[test]
+    fn it_fails_to_verify_a_shifted_opening() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        // Commit phase.
+        let party = SHA256Commitment::new(&s, &r);
+        let commit = party.commit().unwrap();
+
+        // Verification phase, the last byte of the secret is moved to the random number.
+        let shifted_s: [u8; 3] = [52, 50, 52];
+        let shifted_r: [u8; 17] = *b"22424242424242424";
+        let shifted_party = SHA256Commitment::new(&shifted_s, &shifted_r);
+        let verification = shifted_party.verify(&commit, &shifted_s, &shifted_r);
+
+        assert!(verification.is_ok());
+        assert!(!verification.unwrap())
+    }
+
+    /// A secret whose serialization always fails.
+    struct Unserializable;
+
+    impl serde::Serialize for Unserializable {
+        fn serialize<S: serde::Serializer>(&self, _: S) -> Result<S::Ok, S::Error> {
+            Err(serde::ser::Error::custom("unserializable secret"))
+        }
+    }
+
+    #[test]
+    fn it_fails_to_commit_to_an_unserializable_secret() {
+        let s = Unserializable;
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let party = SHA256Commitment::new(&s, &r);
+        let commit = party.commit();
+
+        assert!(matches!(commit, Err(HashCommitmentError::Serialization(_))))
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn it_generates_secure_randomness() {
+        use super::SECURE_RANDOM_LEN;
+
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+
+        let party = SHA256Commitment::with_secure_random(&s);
+        let other_party = SHA256Commitment::with_secure_random(&s);
+
+        assert_eq!(party.randomness().len(), SECURE_RANDOM_LEN);
+        assert_ne!(party.randomness(), other_party.randomness());
+
+        // Verification phase, the generated random number is revealed by the prover.
+        let commit = party.commit().unwrap();
+        let verification = party.verify(&commit, &s, party.randomness());
+
+        assert!(verification.unwrap())
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn it_derives_deterministic_randomness_from_a_seed() {
+        use super::SECURE_RANDOM_LEN;
+
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+
+        let party = SHA256Commitment::with_seeded_random(&s, 42);
+        let same_party = SHA256Commitment::with_seeded_random(&s, 42);
+        let other_party = SHA256Commitment::with_seeded_random(&s, 24);
+
+        assert_eq!(party.randomness().len(), SECURE_RANDOM_LEN);
+        assert_eq!(party.randomness(), same_party.randomness());
+        assert_ne!(party.randomness(), other_party.randomness());
+        assert_eq!(party.commit().unwrap(), same_party.commit().unwrap())
+    }
+
+    #[cfg(feature = "hmac")]
+    #[test]
+    fn it_derives_deterministic_randomness_from_a_key() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+
+        let party = SHA256Commitment::with_derived_random(&s, b"key").unwrap();
+        let same_party = SHA256Commitment::with_derived_random(&s, b"key").unwrap();
+
+        assert_eq!(party.randomness(), same_party.randomness());
+        assert_eq!(party.commit().unwrap(), same_party.commit().unwrap());
+
+        let commit = party.commit().unwrap();
+
+        assert!(party.verify(&commit, &s, party.randomness()).unwrap())
+    }
+
+    #[cfg(feature = "hmac")]
+    #[test]
+    fn it_derives_different_randomness_from_different_keys() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+
+        let party = SHA256Commitment::with_derived_random(&s, b"key").unwrap();
+        let other_party = SHA256Commitment::with_derived_random(&s, b"other key").unwrap();
+
+        assert_ne!(party.randomness(), other_party.randomness());
+        assert_ne!(party.commit().unwrap(), other_party.commit().unwrap())
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn it_commits_over_a_stream_of_secrets() {
+        use super::SECURE_RANDOM_LEN;
+
+        let secrets: Vec<[u8; 4]> = vec![*b"4242", *b"2424", *b"4224"];
+
+        let pairs: Vec<(Commitment, Vec<u8>)> =
+            SHA256Commitment::commit_stream(secrets.clone().into_iter())
+                .collect::<super::Result<_>>()
+                .unwrap();
+
+        assert_eq!(pairs.len(), secrets.len());
+        for (s, (commit, r)) in secrets.iter().zip(&pairs) {
+            assert_eq!(r.len(), SECURE_RANDOM_LEN);
+            assert!(SHA256Commitment::new(s, r).verify(commit, s, r).unwrap())
+        }
+    }
+
+    #[test]
+    fn it_verifies_an_opening() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        // Commit phase.
+        let party = SHA256Commitment::new(&s, &r);
+        let commit = party.commit().unwrap();
+
+        // Open phase.
+        let opening = party.open();
+
+        assert_eq!(opening.secret(), &s);
+        assert_eq!(opening.randomness(), r);
+
+        // Verification phase.
+        let verification = party.verify_opening(&commit, &opening);
+
+        assert!(verification.is_ok());
+        assert!(verification.unwrap())
+    }
+
+    #[test]
+    fn it_commits_in_batch() {
+        let secrets: Vec<[u8; 4]> = (0..64).map(|i| [i, 50, 52, 50]).collect();
+        let randoms: Vec<[u8; 16]> = (0..64).map(|i| [i; 16]).collect();
+        let items: Vec<(&[u8; 4], &[u8])> = secrets
+            .iter()
+            .zip(randoms.iter())
+            .map(|(s, r)| (s, r.as_slice()))
+            .collect();
+
+        let commits = SHA256Commitment::batch_commit(&items).unwrap();
+
+        assert_eq!(commits.len(), items.len());
+        for ((s, r), commit) in items.iter().zip(commits.iter()) {
+            assert_eq!(SHA256Commitment::new(*s, r).commit().unwrap(), *commit);
+        }
+    }
+
+    #[test]
+    fn it_verifies_in_batch() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+        let fake: [u8; 4] = [66, 68, 66, 68];
+
+        let commit = SHA256Commitment::new(&s, &r).commit().unwrap();
+        let other_commit = SHA256Commitment::new(&fake, &r).commit().unwrap();
+
+        let verifications = SHA256Commitment::batch_verify(&[
+            (&commit, &s, &r),
+            (&commit, &s, &fake),
+            (&other_commit, &fake, &r),
+            (&commit, &fake, &r),
+        ]);
+
+        assert_eq!(verifications.unwrap(), vec![true, false, true, false])
+    }
+
+    /// Here, the same opening is committed to using two different serializers. The commitments
+    /// differ, but each of them holds under its own serializer.
+    #[cfg(feature = "json")]
+    #[test]
+    fn it_commits_differently_with_json() {
+        use super::serializer::{BincodeSerializer, JsonSerializer};
+
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let bincode_party = DigestCommitment::<_, Sha256, BincodeSerializer>::new(&s, &r);
+        let json_party = DigestCommitment::<_, Sha256, JsonSerializer>::new(&s, &r);
+
+        let bincode_commit = bincode_party.commit().unwrap();
+        let json_commit = json_party.commit().unwrap();
+
+        assert_ne!(bincode_commit, json_commit);
+        assert!(bincode_party.verify(&bincode_commit, &s, &r).unwrap());
+        assert!(json_party.verify(&json_commit, &s, &r).unwrap());
+        assert!(!json_party.verify(&bincode_commit, &s, &r).unwrap())
+    }
+
+    /// Here, the secret holds integers, which postcard encodes as varints. A byte array would be
+    /// encoded the same way by both serializers.
+    #[cfg(feature = "postcard")]
+    #[test]
+    fn it_commits_differently_with_postcard() {
+        use super::serializer::{BincodeSerializer, PostcardSerializer};
+
+        let s: Vec<u64> = vec![4242, 2424];
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let bincode_party = DigestCommitment::<_, Sha256, BincodeSerializer>::new(&s, &r);
+        let postcard_party = DigestCommitment::<_, Sha256, PostcardSerializer>::new(&s, &r);
+
+        let bincode_commit = bincode_party.commit().unwrap();
+        let postcard_commit = postcard_party.commit().unwrap();
+
+        assert_ne!(bincode_commit, postcard_commit);
+        assert!(postcard_party.verify(&postcard_commit, &s, &r).unwrap());
+        assert!(!postcard_party
+            .verify(&postcard_commit, &vec![4242, 2425], &r)
+            .unwrap());
+        assert!(!postcard_party.verify(&bincode_commit, &s, &r).unwrap())
+    }
+
+    /// Here, the same opening is committed to under two different domains. The commitments
+    /// differ, and a commitment only holds under the domain it was forged for.
+    #[test]
+    fn it_binds_the_domain() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let party = SHA256Commitment::with_domain(&s, &r, b"protocol-a");
+        let other_party = SHA256Commitment::with_domain(&s, &r, b"protocol-b");
+
+        let commit = party.commit().unwrap();
+        let other_commit = other_party.commit().unwrap();
+
+        assert_ne!(commit, other_commit);
+        assert_ne!(commit, SHA256Commitment::new(&s, &r).commit().unwrap());
+        assert!(party.verify(&commit, &s, &r).unwrap());
+        assert!(!other_party.verify(&commit, &s, &r).unwrap())
+    }
+
+    /// Here, the same opening is committed to with two different associated data. A commitment
+    /// only holds under the associated data it was forged for, and differs from a commitment
+    /// using the same bytes as a domain.
+    #[test]
+    fn it_binds_the_associated_data() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let party = SHA256Commitment::with_associated_data(&s, &r, b"session-1");
+        let other_party = SHA256Commitment::with_associated_data(&s, &r, b"session-2");
+
+        let commit = party.commit().unwrap();
+
+        assert!(party.verify(&commit, &s, &r).unwrap());
+        assert!(!other_party.verify(&commit, &s, &r).unwrap());
+        assert_ne!(
+            commit,
+            SHA256Commitment::with_domain(&s, &r, b"session-1")
+                .commit()
+                .unwrap()
+        );
+        assert_ne!(commit, SHA256Commitment::new(&s, &r).commit().unwrap())
+    }
+
+    #[test]
+    fn it_binds_the_index() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let commit = SHA256Commitment::commit_indexed(3, &s, &r).unwrap();
+
+        assert!(SHA256Commitment::verify_indexed(&commit, 3, &s, &r).unwrap());
+        assert!(SHA256Commitment::verify_indexed(commit.as_bytes(), 3, &s, &r).unwrap());
+        assert!(!SHA256Commitment::verify_indexed(&commit, 4, &s, &r).unwrap());
+        assert_ne!(commit, SHA256Commitment::new(&s, &r).commit().unwrap());
+
+        assert_ne!(
+            commit,
+            SHA256Commitment::with_domain(&s, &r, &3u64.to_be_bytes())
+                .commit()
+                .unwrap()
+        );
+
+        let vector = super::vector::VectorCommitment::new(&[(&s, &r[..]); 4]).unwrap();
+
+        assert_eq!(vector.commitments()[3], commit)
+    }
+
+    #[test]
+    fn it_binds_the_round() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let commit = SHA256Commitment::commit_round(1, &s, &r).unwrap();
+
+        assert!(SHA256Commitment::verify_round(&commit, 1, &s, &r).unwrap());
+        assert!(!SHA256Commitment::verify_round(&commit, 2, &s, &r).unwrap());
+        assert_ne!(commit, SHA256Commitment::commit_indexed(1, &s, &r).unwrap())
+    }
+
+    #[test]
+    fn it_binds_the_enum_variant() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let commit = SHA256Commitment::commit_enum(0, &s, &r).unwrap();
+        let other_commit = SHA256Commitment::commit_enum(1, &s, &r).unwrap();
+
+        assert_ne!(commit, other_commit);
+        assert!(SHA256Commitment::verify_enum(&commit, 0, &s, &r).unwrap());
+        assert!(!SHA256Commitment::verify_enum(&commit, 1, &s, &r).unwrap());
+        assert_ne!(commit, SHA256Commitment::commit_round(0, &s, &r).unwrap())
+    }
+
+    #[test]
+    fn it_binds_the_identity() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let commit = SHA256Commitment::commit_bound_to_identity(b"alice", &s, &r).unwrap();
+
+        assert!(SHA256Commitment::verify_bound_to_identity(&commit, b"alice", &s, &r).unwrap());
+        assert!(!SHA256Commitment::verify_bound_to_identity(&commit, b"bob", &s, &r).unwrap());
+        assert_ne!(
+            commit,
+            SHA256Commitment::with_associated_data(&s, &r, b"alice")
+                .commit()
+                .unwrap()
+        )
+    }
+
+    #[test]
+    fn it_measures_the_serialized_secret() {
+        let s: Vec<u8> = vec![42; 1000];
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let party = SHA256Commitment::new(&s, &r);
+        let encoded_s =
+            bincode::serde::encode_to_vec(&s, crate::serializer::BINCODE_CONFIG).unwrap();
+
+        assert_eq!(party.serialized_size().unwrap(), encoded_s.len() as u64);
+        assert_eq!(
+            party.preimage().unwrap(),
+            crate::encoding::PreimageBuilder::new()
+                .field(&encoded_s)
+                .field(&r)
+                .finish()
+        )
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn it_commits_to_a_json_value_whatever_the_order_of_its_keys() {
+        use super::serializer::JsonSerializer;
+
+        let r: [u8; 16] = *b"2424242424242424";
+        let v: serde_json::Value =
+            serde_json::from_str(r#"{"b": 42, "a": {"d": [1, {"f": 2, "e": 3}], "c": null}}"#)
+                .unwrap();
+        let reordered_v: serde_json::Value =
+            serde_json::from_str(r#"{"a": {"c": null, "d": [1, {"e": 3, "f": 2}]}, "b": 42}"#)
+                .unwrap();
+
+        let commit = SHA256Commitment::commit_json_value(&v, &r).unwrap();
+
+        assert_eq!(
+            commit,
+            SHA256Commitment::commit_json_value(&reordered_v, &r).unwrap()
+        );
+        assert_eq!(
+            commit,
+            DigestCommitment::<_, Sha256, JsonSerializer>::new(&reordered_v, &r)
+                .commit()
+                .unwrap()
+        );
+        assert!(SHA256Commitment::verify_json_value(&commit, &reordered_v, &r).unwrap());
+        assert!(
+            !SHA256Commitment::verify_json_value(&commit, &serde_json::json!({"b": 42}), &r)
+                .unwrap()
+        )
+    }
+
+    #[test]
+    fn it_accepts_any_byte_container_as_randomness() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+        let r_vec: Vec<u8> = r.to_vec();
+
+        let commit = SHA256Commitment::new(&s, &r).commit().unwrap();
+        let party = SHA256Commitment::new(&s, &r_vec);
+
+        assert_eq!(party.commit().unwrap(), commit);
+        assert!(party.verify(&commit, &s, &r_vec).unwrap());
+        assert!(party.verify(&commit, &s, &r).unwrap());
+        assert_eq!(
+            SHA256Commitment::with_domain(&s, &r_vec, b"domain")
+                .commit()
+                .unwrap(),
+            SHA256Commitment::with_domain(&s, &r, b"domain")
+                .commit()
+                .unwrap()
+        );
+        assert_eq!(
+            SHA256Commitment::with_associated_data(&s, &r_vec, b"aad")
+                .commit()
+                .unwrap(),
+            SHA256Commitment::with_associated_data(&s, &r, b"aad")
+                .commit()
+                .unwrap()
+        )
+    }
+
+    #[test]
+    fn it_accepts_any_byte_container_as_commitment() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let party = SHA256Commitment::new(&s, &r);
+        let commit = party.commit().unwrap();
+        let commit_vec: Vec<u8> = commit.clone().into();
+        let commit_array: [u8; 32] = commit.clone().try_into().unwrap();
+        let commit_slice: &[u8] = commit.as_bytes();
+
+        assert!(party.verify(&commit, &s, &r).unwrap());
+        assert!(party.verify(&commit_vec, &s, &r).unwrap());
+        assert!(party.verify(&commit_array, &s, &r).unwrap());
+        assert!(party.verify(commit_slice, &s, &r).unwrap());
+        assert!(matches!(
+            party.verify(&commit_slice[..16], &s, &r),
+            Err(HashCommitmentError::LengthMismatch {
+                expected: 32,
+                actual: 16
+            })
+        ))
+    }
+
+    /// The length prefixes are 8 bytes wide on every target, so this digest is the same on
+    /// 32-bit and 64-bit machines.
+    #[test]
+    fn it_encodes_platform_independent_length_prefixes() {
+        use super::length_prefix;
+        use sha2::Digest;
+
+        let field = [0u8; 300];
+
+        assert_eq!(length_prefix(&field), [0, 0, 0, 0, 0, 0, 1, 44]);
+        assert_eq!(
+            Sha256::new()
+                .chain_update(length_prefix(&field))
+                .chain_update(field)
+                .finalize()
+                .as_slice(),
+            hex!("23af300d795c35a59b389757d7f64bc1a64cbfbb902e4ee0601bd2427cd423e6")
+        )
+    }
+
+    #[test]
+    fn it_verifies_bytes_against_an_expected_commitment() {
+        use super::verify_bytes;
+
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let expected = SHA256Commitment::new(&s, &r).commit().unwrap();
+        let mut com = expected.as_bytes().to_vec();
+
+        assert!(verify_bytes(&com, expected.as_bytes()));
+        assert!(!verify_bytes(&com[..31], expected.as_bytes()));
+
+        com[31] ^= 1;
+
+        assert!(!verify_bytes(&com, expected.as_bytes()))
+    }
+
+    /// Here, an opening stored along with its SHA256 commitment is migrated to SHA512.
+    #[test]
+    fn it_recommits_under_another_hash_function() {
+        use super::recommit_with;
+        use sha2::Sha512;
+
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let sha256_commit = recommit_with::<Sha256, _>(&s, &r).unwrap();
+        let sha512_commit = recommit_with::<Sha512, _>(&s, &r).unwrap();
+
+        assert_eq!(sha256_commit.len(), 32);
+        assert_eq!(sha512_commit.len(), 64);
+        assert_eq!(
+            sha256_commit,
+            SHA256Commitment::new(&s, &r).commit().unwrap()
+        );
+        assert_eq!(
+            sha512_commit,
+            SHA512Commitment::new(&s, &r).commit().unwrap()
+        )
+    }
+
+    /// Here, the same entries are inserted in two maps in opposite orders, which makes their
+    /// iteration orders differ.
+    #[cfg(feature = "std")]
+    #[test]
+    fn it_commits_to_maps_deterministically() {
+        use super::commit_map;
+        use std::collections::{BTreeMap, HashMap};
+
+        let r: [u8; 16] = *b"2424242424242424";
+        let entries: Vec<(String, u32)> = (0..64).map(|i| (format!("key-{i}"), i)).collect();
+
+        let map: HashMap<_, _> = entries.iter().cloned().collect();
+        let reversed_map: HashMap<_, _> = entries.iter().rev().cloned().collect();
+        let sorted_map: BTreeMap<_, _> = entries.iter().cloned().collect();
+
+        let commit = commit_map::<Sha256, _, _>(&map, &r).unwrap();
+
+        assert_eq!(
+            commit,
+            commit_map::<Sha256, _, _>(&reversed_map, &r).unwrap()
+        );
+        assert!(SHA256Commitment::new(&sorted_map, &r)
+            .verify(&commit, &sorted_map, &r)
+            .unwrap())
+    }
+
+    #[test]
+    fn it_exposes_the_commitment_length() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        assert_eq!(SHA256Commitment::<[u8; 4]>::COMMITMENT_LEN, 32);
+        assert_eq!(SHA512Commitment::<[u8; 4]>::COMMITMENT_LEN, 64);
+        assert_eq!(
+            SHA512Commitment::new(&s, &r).commit().unwrap().len(),
+            SHA512Commitment::<[u8; 4]>::COMMITMENT_LEN
+        )
+    }
+
+    /// Here, the verifier receives a commitment which has been truncated, it must be rejected
+    /// as malformed rather than as not fulfilled.
+    #[test]
+    fn it_rejects_a_commitment_of_the_wrong_length() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let party = SHA256Commitment::new(&s, &r);
+        let commit = party.commit().unwrap();
+        let truncated_commit = Commitment::from(commit.as_bytes()[..16].to_vec());
+
+        let verification = party.verify(&truncated_commit, &s, &r);
+
+        assert!(matches!(
+            verification,
+            Err(HashCommitmentError::LengthMismatch {
+                expected: 32,
+                actual: 16
+            })
+        ))
+    }
+
+    /// Here, the prover tries to commit using random numbers which are too short to hide its
+    /// secret.
+    #[test]
+    fn it_rejects_insufficient_randomness() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let empty_commit = SHA256Commitment::new(&s, &[]).commit();
+        let short_commit = SHA256Commitment::new(&s, &r[..15]).commit();
+        let commit = SHA256Commitment::new(&s, &r).commit();
+
+        assert!(matches!(
+            empty_commit,
+            Err(HashCommitmentError::InsufficientRandomness { got: 0, min: 16 })
+        ));
+        assert!(matches!(
+            short_commit,
+            Err(HashCommitmentError::InsufficientRandomness { got: 15, min: 16 })
+        ));
+        assert!(commit.is_ok())
+    }
+
+    /// Here, the minimum length of the random number is lowered to verify a legacy commitment
+    /// forged with a short random number. Verification itself never enforces the minimum.
+    #[test]
+    fn it_accepts_a_configured_minimum_randomness() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 4] = [50, 52, 50, 52]; // 2424 in string format.
+
+        let party = SHA256Commitment::new(&s, &r).with_min_randomness(4);
+        let commit = party.commit().unwrap();
+
+        assert!(SHA256Commitment::new(&s, &[])
+            .verify(&commit, &s, &r)
+            .unwrap())
+    }
+
+    /// Here, many commitments are forged both through `commit` and `commit_into` reusing the
+    /// same buffer, the bytes produced must be identical.
+    #[test]
+    fn it_commits_into_a_buffer_like_commit() {
+        let randoms: Vec<[u8; 16]> = (0..1_000u32).map(|i| [(i % 251) as u8; 16]).collect();
+        let mut out = [0u8; 32];
+
+        for (i, r) in randoms.iter().enumerate() {
+            let s = (i as u64, vec![i as u8; i % 64]);
+            let party = SHA256Commitment::new(&s, r);
+
+            party.commit_into(&mut out).unwrap();
+
+            assert_eq!(party.commit().unwrap().as_bytes(), out);
+        }
+    }
+
+    #[test]
+    fn it_verifies_into_an_option() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let party = SHA256Commitment::new(&s, &r);
+        let commit = party.commit().unwrap();
+
+        assert_eq!(party.verify_opt(&commit, &s, &r), Some(true));
+        assert_eq!(party.verify_opt(&commit, &[0u8; 4], &r), Some(false));
+        assert_eq!(
+            party.verify_opt(&Commitment::from(vec![0u8; 16]), &s, &r),
+            Some(false)
+        );
+
+        let unserializable_party = SHA256Commitment::new(&Unserializable, &r);
+
+        assert_eq!(
+            unserializable_party.verify_opt(&commit, &Unserializable, &r),
+            None
+        )
+    }
+
+    /// A serializer which streams the encoded secret to the hasher without its length prefix.
+    struct UnframedSerializer;
+
+    impl super::Serializer for UnframedSerializer {
+        fn serialize<T: serde::Serialize + ?Sized>(value: &T) -> crate::Result<Vec<u8>> {
+            super::BincodeSerializer::serialize(value)
+        }
+
+        fn update_digest<T: serde::Serialize + ?Sized, D: sha2::Digest>(
+            value: &T,
+            hasher: &mut D,
+        ) -> crate::Result<()> {
+            hasher.update(Self::serialize(value)?);
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn it_exports_the_preimage() {
+        use sha2::Digest;
+
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let party = SHA256Commitment::new(&s, &r);
+        let preimage = party.preimage().unwrap();
+
+        assert_eq!(
+            preimage,
+            [
+                &[0, 0, 0, 0, 0, 0, 0, 4][..],
+                &s,
+                &[0, 0, 0, 0, 0, 0, 0, 16],
+                &r,
+            ]
+            .concat()
+        );
+        assert_eq!(
+            Sha256::digest(&preimage).as_slice(),
+            party.commit().unwrap().as_bytes()
+        );
+
+        let other_s: [u8; 4] = [50, 52, 50, 52]; // 2424 in string format.
+        let aad_party = SHA256Commitment::with_associated_data(&s, &r, b"context");
+
+        assert_eq!(
+            Sha256::digest(aad_party.preimage_of(&other_s, &r).unwrap()).as_slice(),
+            aad_party.recommit(&other_s, &r).unwrap().as_bytes()
+        )
+    }
+
+    #[test]
+    fn it_checks_that_the_framing_is_unambiguous() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        assert!(SHA256Commitment::new(&s, &r).check_framing_unambiguous());
+        assert!(SHA256Commitment::with_domain(&s, &r, b"protocol").check_framing_unambiguous());
+        assert!(
+            !DigestCommitment::<_, Sha256, UnframedSerializer>::new(&s, &r)
+                .check_framing_unambiguous()
+        )
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "the serializer does not frame the secret with a length prefix")]
+    fn it_panics_when_committing_without_framing_in_debug_builds() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let _ = DigestCommitment::<_, Sha256, UnframedSerializer>::new(&s, &r).commit();
+    }
+
+    #[test]
+    fn it_truncates_the_commitment() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let party = SHA256Commitment::new(&s, &r);
+        let commit = party.commit_truncated(16).unwrap();
+
+        assert_eq!(commit.len(), 16);
+        assert_eq!(commit.as_bytes(), &party.commit().unwrap().as_bytes()[..16]);
+        assert!(party.verify_truncated(&commit, &s, &r, 16).unwrap());
+        assert!(!party
+            .verify_truncated(&commit, &s, b"4242424242424242", 16)
+            .unwrap());
+        assert!(matches!(
+            party.verify_truncated(&commit, &s, &r, 20),
+            Err(HashCommitmentError::LengthMismatch {
+                expected: 20,
+                actual: 16
+            })
+        ))
+    }
+
+    #[test]
+    fn it_rejects_an_invalid_truncation() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let party = SHA256Commitment::new(&s, &r);
+
+        assert!(matches!(
+            party.commit_truncated(8),
+            Err(HashCommitmentError::InvalidTruncation {
+                len: 8,
+                min: 16,
+                max: 32
+            })
+        ));
+        assert!(matches!(
+            party.commit_truncated(33),
+            Err(HashCommitmentError::InvalidTruncation { len: 33, .. })
+        ))
+    }
+
+    #[test]
+    fn it_commits_to_a_generic_array_like_commit() {
+        use sha2::digest::generic_array::GenericArray;
+        use sha2::digest::typenum::U32;
+
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let party = SHA256Commitment::new(&s, &r);
+        let output: GenericArray<u8, U32> = party.commit_generic().unwrap();
+
+        assert_eq!(output.as_slice(), party.commit().unwrap().as_bytes())
+    }
+
+    #[test]
+    fn it_fails_to_commit_into_a_buffer_of_the_wrong_length() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+        let mut out = [0u8; 16];
+
+        let commit = SHA256Commitment::new(&s, &r).commit_into(&mut out);
+
+        assert!(matches!(
+            commit,
+            Err(HashCommitmentError::LengthMismatch {
+                expected: 32,
+                actual: 16
+            })
+        ))
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn it_commits_asynchronously() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let party = SHA256Commitment::with_domain(&s, &r, b"protocol-a");
+        let commit = party.commit_async().await;
+
+        assert_eq!(commit.unwrap(), party.commit().unwrap());
+        assert!(matches!(
+            SHA256Commitment::new(&s, &r[..8]).commit_async().await,
+            Err(HashCommitmentError::InsufficientRandomness { got: 8, min: 16 })
+        ))
+    }
+
+    #[test]
+    fn it_commits_to_one_secret_with_many_randoms() {
+        let s: Vec<u64> = vec![4242, 2424];
+        let randoms: Vec<[u8; 16]> = (0..4).map(|i| [i; 16]).collect();
+        let randoms: Vec<&[u8]> = randoms.iter().map(|r| r.as_slice()).collect();
+
+        let commits = SHA256Commitment::commit_many_randoms(&s, &randoms).unwrap();
+
+        assert_eq!(commits.len(), randoms.len());
+        for (commit, r) in commits.iter().zip(&randoms) {
+            assert_eq!(*commit, SHA256Commitment::new(&s, r).commit().unwrap());
+        }
+        assert!(SHA256Commitment::commit_many_randoms(&s, &[])
+            .unwrap()
+            .is_empty());
+        assert!(matches!(
+            SHA256Commitment::commit_many_randoms(&s, &[randoms[0], b"24"]),
+            Err(HashCommitmentError::InsufficientRandomness { got: 2, min: 16 })
+        ))
+    }
+
+    #[test]
+    fn it_verifies_a_multi_commitment() {
+        let secrets: [[u8; 4]; 3] = [[52, 50, 52, 50], [66, 68, 66, 68], [1, 2, 3, 4]];
+        let r: [u8; 16] = *b"2424242424242424";
+        let fake: [u8; 4] = [0, 0, 0, 0];
+
+        // Commit phase.
+        let commit =
+            SHA256Commitment::commit_multi(&[&secrets[0], &secrets[1], &secrets[2]], &r).unwrap();
+
+        // Verification phase.
+        assert!(SHA256Commitment::verify_multi(
+            &commit,
+            &[&secrets[0], &secrets[1], &secrets[2]],
+            &r
+        )
+        .unwrap());
+        assert!(
+            !SHA256Commitment::verify_multi(&commit, &[&secrets[0], &fake, &secrets[2]], &r)
+                .unwrap()
+        );
+        assert!(!SHA256Commitment::verify_multi(&commit, &[&secrets[0], &secrets[1]], &r).unwrap())
+    }
+
+    /// Here, the same bytes are committed to as differently ordered or split secrets, each
+    /// arrangement must produce its own commitment.
+    #[test]
+    fn it_binds_the_order_and_boundaries_of_multiple_secrets() {
+        let a: &[u8] = b"4242";
+        let b: &[u8] = b"BDBD";
+        let ab: &[u8] = b"4242BDBD";
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let commit = SHA256Commitment::commit_multi(&[&a, &b], &r).unwrap();
+        let swapped_commit = SHA256Commitment::commit_multi(&[&b, &a], &r).unwrap();
+        let merged_commit = SHA256Commitment::commit_multi(&[&ab], &r).unwrap();
+
+        assert_ne!(commit, swapped_commit);
+        assert_ne!(commit, merged_commit);
+        assert!(!SHA256Commitment::verify_multi(&commit, &[&ab], &r).unwrap())
+    }
+
+    /// Here, the party is formatted as it would be in logs, neither the secret nor the random
+    /// number must appear.
+    #[test]
+    fn it_redacts_the_secret_in_debug_output() {
+        let s = "correct horse battery staple";
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let party = SHA256Commitment::new(&s, &r);
+        let debug = format!("{party:?}");
+
+        assert_eq!(
+            debug,
+            "DigestCommitment { secret: <redacted>, r_len: 16, domain: None, min_randomness: 16 }"
+        );
+        assert!(!debug.contains(s));
+        assert!(!debug.contains("2424"));
+        assert!(!debug.contains(&format!("{:?}", r)))
+    }
+
+    #[test]
+    fn it_recommits_like_commit() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+        let fake: [u8; 4] = [66, 68, 66, 68];
+
+        let party = SHA256Commitment::with_domain(&s, &r, b"protocol-a");
+        let commit = party.commit().unwrap();
+
+        assert_eq!(party.recommit(&s, &r).unwrap(), commit);
+        assert_ne!(party.recommit(&fake, &r).unwrap(), commit);
+
+        // The random number is not checked when recommitting.
+        assert_eq!(
+            party.recommit(&s, &r[..4]).unwrap(),
+            SHA256Commitment::with_domain(&s, &r[..4], b"protocol-a")
+                .with_min_randomness(4)
+                .commit()
+                .unwrap()
+        )
+    }
+
+    #[test]
+    fn it_finds_the_matching_secret() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+        let candidates: Vec<[u8; 4]> = (48..58).map(|i| [52, 50, 52, i]).collect();
+
+        let party = SHA256Commitment::new(&s, &r);
+        let commit = party.commit().unwrap();
+
+        assert_eq!(
+            party
+                .find_matching_secret(&commit, &candidates, &r)
+                .unwrap(),
+            Some(&candidates[2])
+        );
+        assert_eq!(
+            party
+                .find_matching_secret(&commit, &candidates[3..], &r)
+                .unwrap(),
+            None
+        )
+    }
+
+    #[test]
+    fn it_reblinds_a_commitment() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+        let new_r: [u8; 16] = *b"4242424242424242";
+
+        let party = SHA256Commitment::with_domain(&s, &r, b"protocol");
+        let commit = party.commit().unwrap();
+        let new_commit = party.reblind(&new_r).unwrap();
+
+        assert_ne!(commit, new_commit);
+        assert!(party
+            .verify_reblind(&commit, &new_commit, &s, &r, &new_r)
+            .unwrap());
+        assert!(matches!(
+            party.reblind(&new_r[..8]),
+            Err(HashCommitmentError::InsufficientRandomness { got: 8, min: 16 })
+        ))
+    }
+
+    #[test]
+    fn it_rejects_a_reblinding_to_another_secret() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let other_s: [u8; 4] = [50, 52, 50, 52]; // 2424 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+        let new_r: [u8; 16] = *b"4242424242424242";
+
+        let party = SHA256Commitment::new(&s, &r);
+        let commit = party.commit().unwrap();
+        let new_commit = SHA256Commitment::new(&other_s, &new_r).commit().unwrap();
+
+        assert!(!party
+            .verify_reblind(&commit, &new_commit, &s, &r, &new_r)
+            .unwrap());
+        assert!(!party
+            .verify_reblind(&commit, &new_commit, &other_s, &r, &new_r)
+            .unwrap())
+    }
+
+    /// Here, the events are formatted to a shared buffer, which must hold the lengths of the
+    /// secret and of the random number, but neither of them.
+    #[cfg(all(feature = "tracing", feature = "std"))]
+    #[test]
+    fn it_traces_commit_and_verify_without_the_secret() {
+        use std::sync::{Arc, Mutex};
+
+        #[derive(Clone, Default)]
+        struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+        impl std::io::Write for Buffer {
+            fn write(&mut self, bytes: &[u8]) -> std::io::Result<usize> {
+                self.0.lock().unwrap().write(bytes)
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let s = String::from("top-secret-value");
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let buffer = Buffer::default();
+        let writer = buffer.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_max_level(tracing::Level::DEBUG)
+            .with_writer(move || writer.clone())
+            .with_ansi(false)
+            .finish();
+
+        tracing::subscriber::with_default(subscriber, || {
+            let party = SHA256Commitment::new(&s, &r);
+            let commit = party.commit().unwrap();
+
+            assert!(party.verify(&commit, &s, &r).unwrap())
+        });
+
+        let logs = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+
+        assert!(logs.contains("commit{secret_len=24 r_len=16}"));
+        assert!(logs.contains("verify{secret_len=24 r_len=16}"));
+        assert!(logs.contains("matched=true"));
+        assert!(!logs.contains("top-secret-value"));
+        assert!(!logs.contains("2424242424242424"))
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    fn it_explains_a_failed_verification() {
+        use super::{MismatchHint, VerifyOutcome};
+
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let other_s: [u8; 4] = [50, 52, 50, 52]; // 2424 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+        let other_r: [u8; 16] = *b"4242424242424242";
+
+        let party = SHA256Commitment::new(&s, &r);
+        let commit = party.commit().unwrap();
+
+        assert_eq!(
+            party.verify_explained(&commit, &s, &r).unwrap(),
+            VerifyOutcome::Match
+        );
+        assert_eq!(
+            party.verify_explained(&commit, &s, &other_r).unwrap(),
+            VerifyOutcome::Mismatch(MismatchHint::Randomness)
+        );
+        assert_eq!(
+            party.verify_explained(&commit, &other_s, &r).unwrap(),
+            VerifyOutcome::Mismatch(MismatchHint::Secret)
+        );
+        assert_eq!(
+            party.verify_explained(&commit, &other_s, &other_r).unwrap(),
+            VerifyOutcome::Mismatch(MismatchHint::Unknown)
+        )
+    }
+
+    #[test]
+    fn it_verifies_two_commitments_to_the_same_secret() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let other_s: [u8; 4] = [50, 52, 50, 52]; // 2424 in string format.
+        let r1: [u8; 16] = *b"2424242424242424";
+        let r2: [u8; 16] = *b"4242424242424242";
+
+        let party = SHA256Commitment::new(&s, &r1);
+        let commit1 = party.commit().unwrap();
+        let commit2 = SHA256Commitment::new(&s, &r2).commit().unwrap();
+        let other_commit = SHA256Commitment::new(&other_s, &r2).commit().unwrap();
+
+        assert!(party
+            .verify_same_secret(&commit1, &commit2, &s, &r1, &r2)
+            .unwrap());
+        assert!(!party
+            .verify_same_secret(&commit1, &other_commit, &s, &r1, &r2)
+            .unwrap());
+        assert!(!party
+            .verify_same_secret(&commit1, &other_commit, &other_s, &r1, &r2)
+            .unwrap())
+    }
+
+    #[test]
+    fn it_is_not_perfectly_hiding() {
+        fn is_perfectly_hiding<T: serde::Serialize, S: HashCommitmentScheme<T>>(_: &S) -> bool {
+            S::is_perfectly_hiding()
+        }
+
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        assert!(!SHA256Commitment::<[u8; 4]>::is_perfectly_hiding());
+        assert!(!is_perfectly_hiding(&SHA256Commitment::new(&s, &r)))
+    }
+
+    #[test]
+    fn it_commits_through_a_reference() {
+        fn commit_and_verify<T: serde::Serialize, S: HashCommitmentScheme<T>>(
+            party: S,
+            s: &T,
+            r: &[u8],
+        ) -> bool {
+            let commit = party.commit().unwrap();
+
+            commit.len() == S::COMMITMENT_LEN && party.verify(&commit, s, r).unwrap()
+        }
+
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+        let party = SHA256Commitment::new(&s, &r);
+
+        assert!(commit_and_verify(&party, &s, &r));
+        assert_eq!(
+            HashCommitmentScheme::commit(&&party).unwrap(),
+            party.commit().unwrap()
+        )
+    }
+
+    /// Here, one scratch buffer is reused across commitments to secrets of different sizes,
+    /// starting with leftover bytes from a previous use.
+    #[test]
+    fn it_commits_with_a_reused_buffer() {
+        let r: [u8; 16] = *b"2424242424242424";
+        let mut scratch = b"leftover bytes".to_vec();
+
+        for i in [4, 64, 0, 16] {
+            let s = vec![i as u8; i];
+            let party = SHA256Commitment::with_domain(&s, &r, b"protocol-a");
+
+            let commit = party.commit_with_buffer(&mut scratch).unwrap();
+
+            assert_eq!(commit, party.commit().unwrap());
+        }
+    }
+
+    /// Here, the SHA256 commitment is forged as a fixed-size array, which matches the bytes of
+    /// the heap-allocated commitment.
+    #[test]
+    fn it_commits_to_a_fixed_size_output() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let party = SHA256Commitment::new(&s, &r);
+        let output: [u8; 32] = party.commit_output().unwrap().into();
+
+        assert_eq!(
+            output,
+            hex!("4385e32a44ae244b0a65234c7fbbe8e11d51f1ee874b74781a6476960229b546")
+        );
+        assert_eq!(output, party.commit().unwrap().as_bytes());
+        assert_eq!(
+            SHA512Commitment::new(&s, &r).commit_output().unwrap().len(),
+            64
+        );
+        assert!(matches!(
+            SHA256Commitment::new(&s, &r[..8]).commit_output(),
+            Err(HashCommitmentError::InsufficientRandomness { got: 8, min: 16 })
+        ))
     }
 }
@@ -0,0 +1,151 @@
+use crate::serializer::{BincodeSerializer, Serializer};
+use crate::{
+    check_randomness_len, length_prefix, Commitment, HashCommitmentScheme, Opening, Result,
+    DEFAULT_MIN_RANDOMNESS_LEN,
+};
+use alloc::vec::Vec;
+use hmac::{Hmac, Mac};
+use serde::Serialize;
+use sha2::digest::Output;
+use sha2::{Digest, Sha256};
+
+/// An implementation of the Hash Commitment Scheme using HMAC-SHA256.
+///
+/// The MAC key is derived from the random number as `k = SHA256(len(r) || r)`, the length being
+/// encoded as a big-endian u64, and the commitment is the MAC of the encoded secret, `HMAC_k(s)`.
+/// Unlike a plain hash of the concatenation of both fields, this standard construction is not
+/// subject to length-extension. The random number is not used directly as the key, since HMAC
+/// pads short keys with zeros and hashes long ones, so that `r` and `r || 0x00` would open the
+/// same commitment. The commitments produced are 32 bytes long.
+///
+/// This implementation is only available when the `hmac` feature is enabled.
+pub struct HmacCommitment<'a, T: 'a + Serialize> {
+    s: &'a T,
+    r: &'a [u8],
+    min_randomness: usize,
+}
+
+impl<'a, T: 'a + Serialize> HmacCommitment<'a, T> {
+    /// Creates a new party for the HMAC Commitment Scheme using its secret and random number.
+    pub fn new(s: &'a T, r: &'a [u8]) -> HmacCommitment<'a, T> {
+        HmacCommitment {
+            s,
+            r,
+            min_randomness: DEFAULT_MIN_RANDOMNESS_LEN,
+        }
+    }
+
+    /// Sets the minimum length in bytes of the random number accepted at commit time, which
+    /// defaults to [`DEFAULT_MIN_RANDOMNESS_LEN`](crate::DEFAULT_MIN_RANDOMNESS_LEN).
+    pub fn with_min_randomness(mut self, min_randomness: usize) -> Self {
+        self.min_randomness = min_randomness;
+        self
+    }
+
+    /// Reveals the party's secret and random number, to be sent to the verifier during the open
+    /// phase.
+    pub fn open(&self) -> Opening<T>
+    where
+        T: Clone,
+    {
+        Opening::new(self.s.clone(), self.r.to_vec())
+    }
+
+    /// Creates a MAC keyed with the key derived from the random number r, fed with the encoded
+    /// secret s.
+    fn keyed_mac(s: &T, r: &[u8]) -> Result<Hmac<Sha256>> {
+        let binary_encoded_s = BincodeSerializer::serialize(s)?;
+        let key = Sha256::new()
+            .chain_update(length_prefix(r))
+            .chain_update(r)
+            .finalize();
+
+        let mac = <Hmac<Sha256> as Mac>::new_from_slice(&key)
+            .expect("HMAC accepts keys of any length")
+            .chain_update(binary_encoded_s);
+
+        Ok(mac)
+    }
+}
+
+impl<'a, T: 'a + Serialize> HashCommitmentScheme<T> for HmacCommitment<'a, T> {
+    const COMMITMENT_LEN: usize = 32;
+
+    type Output = Output<Sha256>;
+
+    /// Creates the bytes of the commitment used during the commit phase.
+    ///
+    /// Returns a [`HashCommitmentError::InsufficientRandomness`](crate::HashCommitmentError)
+    /// if the random number is too short to hide the secret.
+    fn commit_output(&self) -> Result<Self::Output> {
+        check_randomness_len(self.r, self.min_randomness)?;
+
+        Ok(Self::keyed_mac(self.s, self.r)?.finalize().into_bytes())
+    }
+
+    /// Recomputes the MAC of the secret s keyed with the random number r.
+    fn recommit(&self, s: &T, r: &[u8]) -> Result<Commitment> {
+        let tag: Vec<u8> = Self::keyed_mac(s, r)?.finalize().into_bytes().to_vec();
+
+        Ok(Commitment::from(tag))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HmacCommitment;
+    use crate::HashCommitmentScheme;
+    use hex_literal::hex;
+    use sha2::{Digest, Sha256};
+
+    /// The expected commitment is the HMAC-SHA256 of "4242" keyed with the SHA256 digest of the
+    /// length-prefixed "2424242424242424", as computed by Python's `hmac` module.
+    #[test]
+    fn it_commits_correctly() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let party = HmacCommitment::new(&s, &r);
+        let commit = party.commit();
+
+        assert!(commit.is_ok());
+        assert_eq!(
+            commit.unwrap().as_bytes(),
+            hex!("e4114c90a8f5ce145ca317506da61b1d9b4bd77522724b72940f37d57f48105f")
+        )
+    }
+
+    #[test]
+    fn it_verifies_valid_hmac_commitment() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+        let fake: [u8; 4] = [66, 68, 66, 68];
+
+        // Commit phase.
+        let party = HmacCommitment::new(&s, &r);
+        let commit = party.commit().unwrap();
+
+        // Verification phase.
+        assert!(party.verify_opening(&commit, &party.open()).unwrap());
+        assert!(!party.verify(&commit, &s, &fake).unwrap());
+        assert!(!party.verify(&commit, &fake, &r).unwrap())
+    }
+
+    /// Here, HMAC would pad a random number used directly as its key with zeros, so that the
+    /// same commitment would be opened by the random number followed by a zero byte.
+    #[test]
+    fn it_rejects_a_zero_padded_random() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+        let padded_r: [u8; 17] = *b"2424242424242424\0";
+        let long_r: [u8; 100] = [42; 100];
+
+        let party = HmacCommitment::new(&s, &r);
+        let commit = party.commit().unwrap();
+        let long_commit = HmacCommitment::new(&s, &long_r).commit().unwrap();
+        let hashed_long_r = Sha256::digest(long_r);
+
+        assert!(!party.verify(&commit, &s, &padded_r).unwrap());
+        assert!(!party.verify(&long_commit, &s, &hashed_long_r).unwrap())
+    }
+}
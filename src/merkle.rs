@@ -0,0 +1,324 @@
+//! A Merkle tree commitment over a vector of secrets.
+//!
+//! Each leaf of the tree is the SHA256 hash of the commitment of a secret and its random number,
+//! and each node is the SHA256 hash of the concatenation of its two children. As in RFC 6962,
+//! leaves are prefixed with a 0x00 byte and nodes with a 0x01 byte before being hashed, so that
+//! a node can never be presented as a leaf. The root commits to the whole vector, while a single
+//! secret can later be opened using a proof whose size is logarithmic in the number of secrets.
+
+use crate::{Commitment, HashCommitmentError, HashCommitmentScheme, Result, SHA256Commitment};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use subtle::ConstantTimeEq;
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+/// A Merkle tree built over the commitments of a vector of secrets.
+///
+/// When a level of the tree holds an odd number of nodes, its last node is duplicated so that
+/// any number of secrets can be committed to.
+pub struct MerkleCommitment<T: Serialize> {
+    levels: Vec<Vec<Commitment>>,
+    _secret: PhantomData<T>,
+}
+
+/// The proof that a secret is part of a [`MerkleCommitment`], made of the sibling of each node
+/// on the path from the secret's leaf to the root.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MerkleProof {
+    siblings: Vec<Commitment>,
+}
+
+impl MerkleProof {
+    /// Returns the siblings of the nodes on the path from the leaf to the root.
+    pub fn siblings(&self) -> &[Commitment] {
+        &self.siblings
+    }
+}
+
+impl<T: Serialize + Sync> MerkleCommitment<T> {
+    /// Builds the Merkle tree over the given pairs of secret and random number.
+    ///
+    /// When the `rayon` feature is enabled, the leaves and the nodes of each level are hashed in
+    /// parallel. They are collected in order, so the tree does not depend on the number of
+    /// threads.
+    ///
+    /// Returns a [`HashCommitmentError::EmptyMerkleTree`] if no pair is given.
+    pub fn new(leaves: &[(&T, &[u8])]) -> Result<MerkleCommitment<T>> {
+        if leaves.is_empty() {
+            return Err(HashCommitmentError::EmptyMerkleTree);
+        }
+
+        let leaf_level: Vec<Commitment> = SHA256Commitment::batch_commit(leaves)?
+            .iter()
+            .map(hash_leaf)
+            .collect();
+        let mut levels = vec![leaf_level];
+        while let Some(level) = levels.last().filter(|level| level.len() > 1) {
+            let parents = hash_level(level);
+
+            levels.push(parents);
+        }
+
+        Ok(MerkleCommitment {
+            levels,
+            _secret: PhantomData,
+        })
+    }
+}
+
+impl<T: Serialize> MerkleCommitment<T> {
+    /// Returns the root of the tree, which commits to all the secrets.
+    pub fn root(&self) -> &Commitment {
+        &self.levels[self.levels.len() - 1][0]
+    }
+
+    /// Returns the number of secrets committed to.
+    pub fn len(&self) -> usize {
+        self.levels[0].len()
+    }
+
+    /// Returns true if the tree does not commit to any secret, which never happens.
+    pub fn is_empty(&self) -> bool {
+        self.levels[0].is_empty()
+    }
+
+    /// Creates the proof that the secret at the given index is part of the tree, or `None` if
+    /// the index is out of bounds.
+    pub fn prove(&self, index: usize) -> Option<MerkleProof> {
+        if index >= self.len() {
+            return None;
+        }
+
+        let siblings = self.levels[..self.levels.len() - 1]
+            .iter()
+            .enumerate()
+            .map(|(height, level)| {
+                let sibling = (index >> height) ^ 1;
+                level
+                    .get(sibling)
+                    .unwrap_or(&level[level.len() - 1])
+                    .clone()
+            })
+            .collect();
+
+        Some(MerkleProof { siblings })
+    }
+
+    /// Verifies that the secret s and random number r open the leaf at the given index of the
+    /// tree whose root is given, using the proof provided by the prover.
+    ///
+    /// The root is recomputed from the leaf commitment and the proof, then compared with the
+    /// expected one in constant time.
+    pub fn verify_proof(
+        root: &Commitment,
+        index: usize,
+        s: &T,
+        r: &[u8],
+        proof: &MerkleProof,
+    ) -> Result<bool> {
+        // The index can not have more bits than the height of the tree, otherwise the same
+        // proof would hold for several indices.
+        if index.checked_shr(proof.siblings.len() as u32).unwrap_or(0) != 0 {
+            return Ok(false);
+        }
+
+        let mut node = hash_leaf(&SHA256Commitment::new(s, r).commit()?);
+        for (height, sibling) in proof.siblings.iter().enumerate() {
+            let is_left_child = index.checked_shr(height as u32).unwrap_or(0) & 1 == 0;
+
+            node = if is_left_child {
+                hash_nodes(&node, sibling)
+            } else {
+                hash_nodes(sibling, &node)
+            };
+        }
+
+        Ok(node.as_bytes().ct_eq(root.as_bytes()).into())
+    }
+}
+
+/// Computes the parents of the nodes of a level, the last node being paired with itself when the
+/// level holds an odd number of nodes.
+fn hash_level(level: &[Commitment]) -> Vec<Commitment> {
+    #[cfg(feature = "rayon")]
+    let pairs = level.par_chunks(2);
+    #[cfg(not(feature = "rayon"))]
+    let pairs = level.chunks(2);
+
+    pairs
+        .map(|pair| hash_nodes(&pair[0], pair.last().unwrap()))
+        .collect()
+}
+
+/// The byte prefixed to the commitment of a secret when hashing a leaf of the tree.
+const LEAF_PREFIX: u8 = 0x00;
+
+/// The byte prefixed to the children of a node when hashing it.
+const NODE_PREFIX: u8 = 0x01;
+
+/// Computes the leaf of the tree holding the commitment of a secret.
+fn hash_leaf(commitment: &Commitment) -> Commitment {
+    let hash = Sha256::new()
+        .chain_update([LEAF_PREFIX])
+        .chain_update(commitment.as_bytes())
+        .finalize();
+
+    Commitment::from(hash.to_vec())
+}
+
+/// Computes the parent of two nodes of the tree.
+fn hash_nodes(left: &Commitment, right: &Commitment) -> Commitment {
+    let hash = Sha256::new()
+        .chain_update([NODE_PREFIX])
+        .chain_update(left.as_bytes())
+        .chain_update(right.as_bytes())
+        .finalize();
+
+    Commitment::from(hash.to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MerkleCommitment;
+    use crate::{Commitment, HashCommitmentError};
+
+    fn leaves() -> (Vec<[u8; 4]>, Vec<[u8; 16]>) {
+        let secrets = (0..5).map(|i| [i, 50, 52, 50]).collect();
+        let randoms = (0..5).map(|i| [i; 16]).collect();
+
+        (secrets, randoms)
+    }
+
+    #[test]
+    fn it_opens_every_leaf() {
+        let (secrets, randoms) = leaves();
+        let items: Vec<(&[u8; 4], &[u8])> = secrets
+            .iter()
+            .zip(randoms.iter())
+            .map(|(s, r)| (s, r.as_slice()))
+            .collect();
+
+        let tree = MerkleCommitment::new(&items).unwrap();
+
+        assert_eq!(tree.len(), 5);
+        for (index, (s, r)) in items.iter().enumerate() {
+            let proof = tree.prove(index).unwrap();
+
+            assert_eq!(proof.siblings().len(), 3);
+            assert!(MerkleCommitment::verify_proof(tree.root(), index, *s, r, &proof).unwrap());
+        }
+        assert!(tree.prove(5).is_none())
+    }
+
+    #[test]
+    fn it_rejects_a_tampered_proof() {
+        let (secrets, randoms) = leaves();
+        let items: Vec<(&[u8; 4], &[u8])> = secrets
+            .iter()
+            .zip(randoms.iter())
+            .map(|(s, r)| (s, r.as_slice()))
+            .collect();
+
+        let tree = MerkleCommitment::new(&items).unwrap();
+        let mut proof = tree.prove(2).unwrap();
+        proof.siblings[1] = Commitment::from(vec![0u8; 32]);
+
+        let verification =
+            MerkleCommitment::verify_proof(tree.root(), 2, &secrets[2], &randoms[2], &proof);
+        let wrong_index = MerkleCommitment::verify_proof(
+            tree.root(),
+            3,
+            &secrets[2],
+            &randoms[2],
+            &tree.prove(2).unwrap(),
+        );
+
+        assert!(!verification.unwrap());
+        assert!(!wrong_index.unwrap())
+    }
+
+    #[test]
+    fn it_prefixes_leaves_and_nodes() {
+        use crate::{HashCommitmentScheme, SHA256Commitment};
+        use sha2::{Digest, Sha256};
+
+        let (secrets, randoms) = leaves();
+        let items: Vec<(&[u8; 4], &[u8])> =
+            vec![(&secrets[0], &randoms[0]), (&secrets[1], &randoms[1])];
+        let commits: Vec<Commitment> = items
+            .iter()
+            .map(|(s, r)| SHA256Commitment::new(*s, r).commit().unwrap())
+            .collect();
+
+        let tree = MerkleCommitment::new(&items).unwrap();
+        let hash_leaf = |commit: &Commitment| {
+            Sha256::new()
+                .chain_update([0])
+                .chain_update(commit.as_bytes())
+                .finalize()
+        };
+        let root = Sha256::new()
+            .chain_update([1])
+            .chain_update(hash_leaf(&commits[0]))
+            .chain_update(hash_leaf(&commits[1]))
+            .finalize();
+
+        assert_eq!(tree.root().as_bytes(), root.as_slice());
+        assert_ne!(
+            tree.root().as_bytes(),
+            Sha256::new()
+                .chain_update(commits[0].as_bytes())
+                .chain_update(commits[1].as_bytes())
+                .finalize()
+                .as_slice()
+        )
+    }
+
+    #[test]
+    fn it_fails_to_build_an_empty_tree() {
+        let tree = MerkleCommitment::<[u8; 4]>::new(&[]);
+
+        assert!(matches!(tree, Err(HashCommitmentError::EmptyMerkleTree)))
+    }
+
+    /// Here, the tree is built on thread pools of different sizes, and its root is compared with
+    /// the one computed sequentially.
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn it_builds_the_same_tree_in_parallel() {
+        use super::{hash_leaf, hash_nodes};
+        use crate::{HashCommitmentScheme, SHA256Commitment};
+
+        for count in [1u32, 2, 3, 7, 8, 100, 1000] {
+            let secrets: Vec<[u8; 4]> = (0..count).map(u32::to_be_bytes).collect();
+            let r = [42u8; 16];
+            let items: Vec<(&[u8; 4], &[u8])> = secrets.iter().map(|s| (s, &r[..])).collect();
+
+            let mut level: Vec<Commitment> = secrets
+                .iter()
+                .map(|s| hash_leaf(&SHA256Commitment::new(s, &r).commit().unwrap()))
+                .collect();
+            while level.len() > 1 {
+                level = level
+                    .chunks(2)
+                    .map(|pair| hash_nodes(&pair[0], pair.last().unwrap()))
+                    .collect();
+            }
+
+            for threads in [1, 4] {
+                let pool = rayon::ThreadPoolBuilder::new()
+                    .num_threads(threads)
+                    .build()
+                    .unwrap();
+                let tree = pool.install(|| MerkleCommitment::new(&items).unwrap());
+
+                assert_eq!(tree.root(), &level[0]);
+            }
+        }
+    }
+}
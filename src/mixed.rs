@@ -0,0 +1,105 @@
+use crate::{
+    Commitment, DigestCommitment, HashCommitmentScheme, Opening, Result, DEFAULT_MIN_RANDOMNESS_LEN,
+};
+use core::marker::PhantomData;
+use serde::Serialize;
+use sha2::digest::typenum::Unsigned;
+use sha2::digest::Output;
+use sha2::{Digest, Sha256};
+
+/// An implementation of the Hash Commitment Scheme which owns the party's secret but borrows its
+/// random number.
+///
+/// This suits a short-lived secret committed to with a random number taken from a long-lived
+/// buffer: unlike [`DigestCommitment`], the secret does not have to be kept alive separately,
+/// and unlike [`OwnedDigestCommitment`](crate::OwnedDigestCommitment) the random number is not
+/// copied. The commitment is forged by borrowing the secret, using the same framing as
+/// [`DigestCommitment`].
+pub struct OwnedSecretCommitment<'a, T: Serialize, D: Digest> {
+    s: T,
+    r: &'a [u8],
+    min_randomness: usize,
+    _digest: PhantomData<D>,
+}
+
+/// An implementation of the Hash Commitment Scheme using the SHA256 hash function, which owns
+/// the party's secret but borrows its random number.
+pub type OwnedSecretSHA256Commitment<'a, T> = OwnedSecretCommitment<'a, T, Sha256>;
+
+impl<'a, T: Serialize, D: Digest> OwnedSecretCommitment<'a, T, D> {
+    /// Creates a new party for the Commitment Scheme, taking ownership of its secret and
+    /// borrowing its random number.
+    pub fn from_owned_secret(s: T, r: &'a [u8]) -> OwnedSecretCommitment<'a, T, D> {
+        OwnedSecretCommitment {
+            s,
+            r,
+            min_randomness: DEFAULT_MIN_RANDOMNESS_LEN,
+            _digest: PhantomData,
+        }
+    }
+
+    /// Returns the secret owned by the party.
+    pub fn secret(&self) -> &T {
+        &self.s
+    }
+
+    /// Sets the minimum length in bytes of the random number accepted at commit time, which
+    /// defaults to [`DEFAULT_MIN_RANDOMNESS_LEN`](crate::DEFAULT_MIN_RANDOMNESS_LEN).
+    pub fn with_min_randomness(mut self, min_randomness: usize) -> Self {
+        self.min_randomness = min_randomness;
+        self
+    }
+
+    /// Reveals the party's secret and random number, to be sent to the verifier during the open
+    /// phase. The party is consumed, so the secret is moved into the opening.
+    pub fn open(self) -> Opening<T> {
+        Opening::new(self.s, self.r.to_vec())
+    }
+
+    /// Creates a borrowing party over the owned secret.
+    fn borrowed(&self) -> DigestCommitment<'_, T, D> {
+        DigestCommitment::new(&self.s, self.r).with_min_randomness(self.min_randomness)
+    }
+}
+
+impl<'a, T: Serialize, D: Digest> HashCommitmentScheme<T> for OwnedSecretCommitment<'a, T, D> {
+    const COMMITMENT_LEN: usize = D::OutputSize::USIZE;
+
+    type Output = Output<D>;
+
+    /// Creates the bytes of the commitment used during the commit phase.
+    ///
+    /// Returns a [`HashCommitmentError::InsufficientRandomness`](crate::HashCommitmentError)
+    /// if the random number is too short to hide the secret.
+    fn commit_output(&self) -> Result<Self::Output> {
+        self.borrowed().commit_output()
+    }
+
+    fn recommit(&self, s: &T, r: &[u8]) -> Result<Commitment> {
+        self.borrowed().recommit(s, r)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OwnedSecretSHA256Commitment;
+    use crate::{HashCommitmentScheme, SHA256Commitment};
+
+    #[test]
+    fn it_commits_to_an_owned_secret_with_borrowed_randomness() {
+        let r: Vec<u8> = b"2424242424242424".to_vec();
+
+        let party = OwnedSecretSHA256Commitment::from_owned_secret(vec![52u8, 50, 52, 50], &r);
+        let commit = party.commit().unwrap();
+
+        let s: Vec<u8> = vec![52, 50, 52, 50]; // 4242 in string format.
+
+        assert_eq!(commit, SHA256Commitment::new(&s, &r).commit().unwrap());
+        assert!(party.verify(&commit, &s, &r).unwrap());
+
+        let opening = party.open();
+
+        assert_eq!(opening.secret(), &s);
+        assert_eq!(opening.randomness(), r)
+    }
+}
@@ -0,0 +1,67 @@
+use alloc::vec::Vec;
+
+#[cfg(feature = "serde-derive")]
+use serde::{Deserialize, Serialize};
+
+/// The values revealed by the prover during the open phase of a Hash Commitment Scheme.
+///
+/// Keeping the secret and the random number together avoids mismatching the random number used
+/// to verify a commitment with the one which was used to forge it.
+///
+/// When the `serde-derive` feature is enabled, openings can be serialized to be sent over the
+/// wire, the random number being encoded as a byte array.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-derive", derive(Serialize, Deserialize))]
+pub struct Opening<T> {
+    s: T,
+    #[cfg_attr(feature = "serde-derive", serde(with = "serde_bytes"))]
+    r: Vec<u8>,
+}
+
+impl<T> Opening<T> {
+    /// Creates a new opening from the prover's secret and random number.
+    pub fn new(s: T, r: Vec<u8>) -> Opening<T> {
+        Opening { s, r }
+    }
+
+    /// Returns the secret revealed by the prover.
+    pub fn secret(&self) -> &T {
+        &self.s
+    }
+
+    /// Returns the random number revealed by the prover.
+    pub fn randomness(&self) -> &[u8] {
+        &self.r
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "serde-derive")]
+    #[test]
+    fn it_round_trips_through_json() {
+        use super::Opening;
+        use crate::{Commitment, HashCommitmentScheme, SHA256Commitment};
+
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        // Commit phase.
+        let party = SHA256Commitment::new(&s, &r);
+        let commit = party.commit().unwrap();
+        let encoded_commit = serde_json::to_string(&commit).unwrap();
+
+        // Open phase.
+        let encoded_opening = serde_json::to_string(&party.open()).unwrap();
+
+        // Verification phase.
+        let decoded_commit: Commitment = serde_json::from_str(&encoded_commit).unwrap();
+        let decoded_opening: Opening<[u8; 4]> = serde_json::from_str(&encoded_opening).unwrap();
+
+        assert_eq!(decoded_commit, commit);
+        assert_eq!(decoded_opening, party.open());
+        assert!(party
+            .verify_opening(&decoded_commit, &decoded_opening)
+            .unwrap())
+    }
+}
@@ -0,0 +1,28 @@
+/// The outcome of a verification explained by
+/// [`DigestCommitment::verify_explained`](crate::DigestCommitment::verify_explained).
+///
+/// This type is only available in debug builds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VerifyOutcome {
+    /// The secret and the random number open the commitment.
+    Match,
+    /// The secret and the random number do not open the commitment, along with a best-effort
+    /// hint of which of them is wrong.
+    Mismatch(MismatchHint),
+}
+
+/// The part of an opening which most likely does not match the commitment.
+///
+/// This type is only available in debug builds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MismatchHint {
+    /// The party's own secret opens the commitment with the given random number, so the given
+    /// secret is wrong.
+    Secret,
+    /// The given secret opens the commitment with the party's own random number, so the given
+    /// random number is wrong.
+    Randomness,
+    /// Neither the party's own secret nor its random number open the commitment along with the
+    /// given ones, so both may be wrong, or the commitment was forged by another party.
+    Unknown,
+}
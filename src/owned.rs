@@ -0,0 +1,171 @@
+use crate::serializer::{BincodeSerializer, Serializer};
+use crate::{
+    check_randomness_len, length_prefix, Commitment, HashCommitmentScheme, Opening, Result,
+    DEFAULT_MIN_RANDOMNESS_LEN,
+};
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use serde::Serialize;
+use sha2::digest::typenum::Unsigned;
+use sha2::digest::Output;
+use sha2::{Digest, Sha256};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// An implementation of the Hash Commitment Scheme which owns the party's secret and random
+/// number.
+///
+/// Unlike [`DigestCommitment`](crate::DigestCommitment), which only holds references, this
+/// structure takes ownership of the secret and the random number so that it can wipe them from
+/// memory once it is dropped. This is useful when committing to sensitive values such as private
+/// keys.
+///
+/// The intermediate buffer holding the encoded secret is wiped as well once it has been hashed.
+///
+/// This implementation is only available when the `zeroize` feature is enabled.
+pub struct OwnedDigestCommitment<T: Serialize + Zeroize, D: Digest> {
+    s: T,
+    r: Vec<u8>,
+    min_randomness: usize,
+    _digest: PhantomData<D>,
+}
+
+/// An implementation of the Hash Commitment Scheme using the SHA256 hash function, which owns
+/// the party's secret and random number.
+pub type OwnedSHA256Commitment<T> = OwnedDigestCommitment<T, Sha256>;
+
+impl<T: Serialize + Zeroize, D: Digest> OwnedDigestCommitment<T, D> {
+    /// Creates a new party for the Commitment Scheme, taking ownership of its secret and random
+    /// number.
+    pub fn new(s: T, r: Vec<u8>) -> OwnedDigestCommitment<T, D> {
+        OwnedDigestCommitment {
+            s,
+            r,
+            min_randomness: DEFAULT_MIN_RANDOMNESS_LEN,
+            _digest: PhantomData,
+        }
+    }
+
+    /// Returns the random number used by the party to forge its commitment.
+    pub fn randomness(&self) -> &[u8] {
+        &self.r
+    }
+
+    /// Sets the minimum length in bytes of the random number accepted at commit time, which
+    /// defaults to [`DEFAULT_MIN_RANDOMNESS_LEN`](crate::DEFAULT_MIN_RANDOMNESS_LEN).
+    pub fn with_min_randomness(mut self, min_randomness: usize) -> Self {
+        self.min_randomness = min_randomness;
+        self
+    }
+
+    /// Reveals the party's secret and random number, to be sent to the verifier during the open
+    /// phase.
+    ///
+    /// The opening holds copies of the secret and the random number, which are not wiped when
+    /// the party is dropped.
+    pub fn open(&self) -> Opening<T>
+    where
+        T: Clone,
+    {
+        Opening::new(self.s.clone(), self.r.clone())
+    }
+
+    /// Forges a commitment given a secret s and a random number r.
+    ///
+    /// The framing is the same as the one used by [`DigestCommitment`](crate::DigestCommitment),
+    /// but the encoded secret is wiped from memory once it has been hashed. Its buffer is sized
+    /// up front, so that it is never reallocated while the secret is encoded: a reallocation
+    /// would free a partial copy of the encoded secret without wiping it.
+    fn forge_commitment(&self, s: &T, r: &[u8]) -> Result<Output<D>> {
+        let mut binary_encoded_s = Vec::with_capacity(BincodeSerializer::serialized_len(s)?);
+        if let Err(e) = BincodeSerializer::serialize_into(s, &mut binary_encoded_s) {
+            binary_encoded_s.zeroize();
+            return Err(e);
+        }
+
+        let hash = D::new()
+            .chain_update(length_prefix(&binary_encoded_s))
+            .chain_update(binary_encoded_s.as_slice())
+            .chain_update(length_prefix(r))
+            .chain_update(r)
+            .finalize();
+
+        binary_encoded_s.zeroize();
+
+        Ok(hash)
+    }
+}
+
+impl<T: Serialize + Zeroize, D: Digest> HashCommitmentScheme<T> for OwnedDigestCommitment<T, D> {
+    const COMMITMENT_LEN: usize = D::OutputSize::USIZE;
+
+    type Output = Output<D>;
+
+    /// Creates the bytes of the commitment used during the commit phase.
+    ///
+    /// Returns a [`HashCommitmentError::InsufficientRandomness`](crate::HashCommitmentError)
+    /// if the random number is too short to hide the secret.
+    fn commit_output(&self) -> Result<Self::Output> {
+        check_randomness_len(&self.r, self.min_randomness)?;
+
+        self.forge_commitment(&self.s, &self.r)
+    }
+
+    fn recommit(&self, s: &T, r: &[u8]) -> Result<Commitment> {
+        self.forge_commitment(s, r)
+            .map(|hash| Commitment::from(hash.as_slice()))
+    }
+}
+
+impl<T: Serialize + Zeroize, D: Digest> Zeroize for OwnedDigestCommitment<T, D> {
+    fn zeroize(&mut self) {
+        self.s.zeroize();
+        self.r.zeroize();
+    }
+}
+
+impl<T: Serialize + Zeroize, D: Digest> Drop for OwnedDigestCommitment<T, D> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<T: Serialize + Zeroize, D: Digest> ZeroizeOnDrop for OwnedDigestCommitment<T, D> {}
+
+#[cfg(test)]
+mod tests {
+    use super::OwnedSHA256Commitment;
+    use crate::{HashCommitmentScheme, SHA256Commitment};
+    use zeroize::{Zeroize, ZeroizeOnDrop};
+
+    fn assert_zeroize_on_drop<Z: ZeroizeOnDrop>() {}
+
+    #[test]
+    fn it_implements_zeroize_on_drop() {
+        assert_zeroize_on_drop::<OwnedSHA256Commitment<[u8; 4]>>();
+    }
+
+    /// The owned party must produce the same commitments as the borrowing one.
+    #[test]
+    fn it_commits_like_the_borrowing_implementation() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let party = OwnedSHA256Commitment::new(s, r.to_vec());
+        let commit = party.commit().unwrap();
+
+        assert_eq!(commit, SHA256Commitment::new(&s, &r).commit().unwrap());
+        assert!(party.verify(&commit, &s, &r).unwrap())
+    }
+
+    #[test]
+    fn it_wipes_the_secret_and_randomness() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let mut party = OwnedSHA256Commitment::new(s, r.to_vec());
+        party.zeroize();
+
+        assert_eq!(party.s, [0u8; 4]);
+        assert!(party.randomness().is_empty())
+    }
+}
@@ -0,0 +1,241 @@
+//! Helpers for the bookkeeping of interactive protocols built on commitments.
+//!
+//! A [`Transcript`] records the two phases of a commitment from the verifier's point of view:
+//! the commitment received during the commit phase, then the opening received during the open
+//! phase, which is only recorded once it has been verified against the commitment.
+
+use crate::{Commitment, HashCommitmentError, HashCommitmentScheme, Opening, Result};
+use alloc::vec::Vec;
+use serde::Serialize;
+
+/// The record of a commit and reveal round with one prover.
+///
+/// This is useful for protocols such as coin flipping, where each party commits to a value
+/// before all the values are revealed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Transcript<T> {
+    commitment: Option<Commitment>,
+    opening: Option<Opening<T>>,
+}
+
+impl<T: Serialize> Transcript<T> {
+    /// Creates an empty transcript.
+    pub fn new() -> Transcript<T> {
+        Transcript {
+            commitment: None,
+            opening: None,
+        }
+    }
+
+    /// Records the commitment sent by the prover during the commit phase.
+    ///
+    /// Returns a [`HashCommitmentError::AlreadyCommitted`] if a commitment has already been
+    /// recorded, so that the prover can not change its mind once it has committed.
+    pub fn commit(&mut self, com: Commitment) -> Result<()> {
+        if self.commitment.is_some() {
+            return Err(HashCommitmentError::AlreadyCommitted);
+        }
+
+        self.commitment = Some(com);
+
+        Ok(())
+    }
+
+    /// Verifies the secret s and random number r revealed by the prover against the recorded
+    /// commitment using the given scheme, then records them.
+    ///
+    /// Returns a [`HashCommitmentError::MissingCommitment`] if no commitment has been recorded
+    /// yet, and a [`HashCommitmentError::InvalidOpening`] if the opening does not match the
+    /// recorded commitment, in which case it is not recorded.
+    pub fn reveal<S: HashCommitmentScheme<T>>(
+        &mut self,
+        scheme: &S,
+        s: T,
+        r: Vec<u8>,
+    ) -> Result<()> {
+        let com = self
+            .commitment
+            .as_ref()
+            .ok_or(HashCommitmentError::MissingCommitment)?;
+
+        if !scheme.verify(com, &s, &r)? {
+            return Err(HashCommitmentError::InvalidOpening);
+        }
+
+        self.opening = Some(Opening::new(s, r));
+
+        Ok(())
+    }
+
+    /// Returns the commitment recorded during the commit phase, if any.
+    pub fn commitment(&self) -> Option<&Commitment> {
+        self.commitment.as_ref()
+    }
+
+    /// Returns the opening recorded during the open phase, if any.
+    pub fn opening(&self) -> Option<&Opening<T>> {
+        self.opening.as_ref()
+    }
+
+    /// Returns true once a valid opening has been revealed for the recorded commitment.
+    pub fn is_complete(&self) -> bool {
+        self.opening.is_some()
+    }
+}
+
+impl<T: Serialize> Default for Transcript<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Two openings of the same commitment revealing different secrets, found by
+/// [`HashCommitmentScheme::detect_equivocation`].
+///
+/// This proves that the prover broke the binding property of the scheme, which requires finding
+/// a collision of the hash function. It can therefore only be produced for a weak hash function,
+/// and serves as a fraud proof against the prover.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Equivocation<T> {
+    first: Opening<T>,
+    second: Opening<T>,
+}
+
+impl<T> Equivocation<T> {
+    pub(crate) fn new(first: Opening<T>, second: Opening<T>) -> Equivocation<T> {
+        Equivocation { first, second }
+    }
+
+    /// Returns the first opening revealed by the prover.
+    pub fn first(&self) -> &Opening<T> {
+        &self.first
+    }
+
+    /// Returns the second opening revealed by the prover, whose secret differs from the first
+    /// one.
+    pub fn second(&self) -> &Opening<T> {
+        &self.second
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Transcript;
+    use crate::{Commitment, HashCommitmentError, HashCommitmentScheme, Result, SHA256Commitment};
+
+    #[test]
+    fn it_walks_a_full_transcript() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+        let party = SHA256Commitment::new(&s, &r);
+        let mut transcript = Transcript::new();
+
+        // Commit phase.
+        transcript.commit(party.commit().unwrap()).unwrap();
+
+        assert!(!transcript.is_complete());
+
+        // Open phase.
+        transcript.reveal(&party, s, r.to_vec()).unwrap();
+
+        // Verification phase.
+        assert!(transcript.is_complete());
+        assert_eq!(transcript.opening().unwrap().secret(), &s);
+        assert!(party
+            .verify_opening(
+                transcript.commitment().unwrap(),
+                transcript.opening().unwrap()
+            )
+            .unwrap())
+    }
+
+    #[test]
+    fn it_rejects_a_mismatched_reveal() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+        let fake: [u8; 4] = [66, 68, 66, 68];
+        let party = SHA256Commitment::new(&s, &r);
+        let mut transcript = Transcript::new();
+
+        assert!(matches!(
+            transcript.reveal(&party, s, r.to_vec()),
+            Err(HashCommitmentError::MissingCommitment)
+        ));
+
+        transcript.commit(party.commit().unwrap()).unwrap();
+
+        assert!(matches!(
+            transcript.commit(party.commit().unwrap()),
+            Err(HashCommitmentError::AlreadyCommitted)
+        ));
+        assert!(matches!(
+            transcript.reveal(&party, fake, r.to_vec()),
+            Err(HashCommitmentError::InvalidOpening)
+        ));
+        assert!(!transcript.is_complete())
+    }
+
+    /// A deliberately weak scheme keeping a single byte of the SHA256 commitment, so that two
+    /// openings of the same commitment can be found.
+    struct WeakCommitment {
+        s: u64,
+        r: [u8; 16],
+    }
+
+    impl HashCommitmentScheme<u64> for WeakCommitment {
+        const COMMITMENT_LEN: usize = 1;
+
+        type Output = Vec<u8>;
+
+        fn commit_output(&self) -> Result<Self::Output> {
+            let commit = SHA256Commitment::new(&self.s, &self.r).commit()?;
+
+            Ok(commit.as_bytes()[..1].to_vec())
+        }
+
+        fn recommit(&self, s: &u64, r: &[u8]) -> Result<Commitment> {
+            let commit = SHA256Commitment::new(s, r).commit()?;
+
+            Ok(Commitment::from(&commit.as_bytes()[..1]))
+        }
+    }
+
+    #[test]
+    fn it_detects_an_equivocation() {
+        let r: [u8; 16] = *b"2424242424242424";
+        let party = WeakCommitment { s: 4242, r };
+        let com = party.commit().unwrap();
+        let other_s = (0..)
+            .find(|s| *s != 4242 && party.verify(&com, s, &r).unwrap())
+            .unwrap();
+
+        let equivocation = party
+            .detect_equivocation(&com, &4242, &r, &other_s, &r)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(equivocation.first().secret(), &4242);
+        assert_eq!(equivocation.second().secret(), &other_s);
+        assert!(party
+            .detect_equivocation(&com, &4242, &r, &4242, &r)
+            .unwrap()
+            .is_none())
+    }
+
+    #[test]
+    fn it_does_not_flag_an_honest_prover() {
+        let s: u64 = 4242;
+        let r: [u8; 16] = *b"2424242424242424";
+        let party = SHA256Commitment::new(&s, &r);
+        let com = party.commit().unwrap();
+
+        assert!(party
+            .detect_equivocation(&com, &s, &r, &s, &r)
+            .unwrap()
+            .is_none());
+        assert!(party
+            .detect_equivocation(&com, &s, &r, &2424, &r)
+            .unwrap()
+            .is_none())
+    }
+}
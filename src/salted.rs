@@ -0,0 +1,126 @@
+use crate::{
+    check_commitment_len, Commitment, DigestCommitment, HashCommitmentScheme, Opening, Result,
+    DEFAULT_MIN_RANDOMNESS_LEN,
+};
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use rand::{rngs::OsRng, RngCore};
+use serde::Serialize;
+use sha2::digest::typenum::Unsigned;
+use sha2::{Digest, Sha256};
+
+/// The length in bytes of the salts prepended to the salted commitments.
+pub const SALT_LEN: usize = 16;
+
+/// An implementation of the Hash Commitment Scheme which salts each commitment, so that
+/// identical pairs of secret and random number produce distinct public records.
+///
+/// A fresh random salt is generated each time a commitment is forged, and bound into the
+/// hash as the domain-separation tag of a [`DigestCommitment`]: the commitment is
+/// `salt || H(salt || s || r)`. The verifier splits the salt off the commitment to recompute the
+/// digest.
+///
+/// As the salt is generated at commit time, the expected commitment can not be recomputed from
+/// the opening alone, hence the inherent commit and verify methods rather than an
+/// implementation of [`HashCommitmentScheme`].
+///
+/// This implementation is only available when the `rand` feature is enabled.
+pub struct SaltedCommitment<'a, T: 'a + Serialize, D: Digest> {
+    s: &'a T,
+    r: &'a [u8],
+    min_randomness: usize,
+    _digest: PhantomData<D>,
+}
+
+/// An implementation of the salted Hash Commitment Scheme using the SHA256 hash function.
+///
+/// The commitments produced are 48 bytes long.
+pub type SHA256SaltedCommitment<'a, T> = SaltedCommitment<'a, T, Sha256>;
+
+impl<'a, T: 'a + Serialize, D: Digest> SaltedCommitment<'a, T, D> {
+    /// The length in bytes of the commitments produced by the scheme, salt included.
+    pub const COMMITMENT_LEN: usize = SALT_LEN + D::OutputSize::USIZE;
+
+    /// Creates a new party for the salted Commitment Scheme using its secret and random number.
+    pub fn new(s: &'a T, r: &'a [u8]) -> SaltedCommitment<'a, T, D> {
+        SaltedCommitment {
+            s,
+            r,
+            min_randomness: DEFAULT_MIN_RANDOMNESS_LEN,
+            _digest: PhantomData,
+        }
+    }
+
+    /// Sets the minimum length in bytes of the random number accepted at commit time, which
+    /// defaults to [`DEFAULT_MIN_RANDOMNESS_LEN`](crate::DEFAULT_MIN_RANDOMNESS_LEN).
+    pub fn with_min_randomness(mut self, min_randomness: usize) -> Self {
+        self.min_randomness = min_randomness;
+        self
+    }
+
+    /// Reveals the party's secret and random number, to be sent to the verifier during the open
+    /// phase.
+    pub fn open(&self) -> Opening<T>
+    where
+        T: Clone,
+    {
+        Opening::new(self.s.clone(), self.r.to_vec())
+    }
+
+    /// Creates the commitment used during the commit phase, using a freshly generated salt.
+    ///
+    /// Returns a [`HashCommitmentError::InsufficientRandomness`](crate::HashCommitmentError)
+    /// if the random number is too short to hide the secret.
+    pub fn commit(&self) -> Result<Commitment> {
+        let mut salt = [0u8; SALT_LEN];
+        OsRng.fill_bytes(&mut salt);
+
+        let digest = DigestCommitment::<T, D>::with_domain(self.s, self.r, &salt)
+            .with_min_randomness(self.min_randomness)
+            .commit()?;
+
+        let mut salted_commitment = Vec::with_capacity(Self::COMMITMENT_LEN);
+        salted_commitment.extend_from_slice(&salt);
+        salted_commitment.extend_from_slice(digest.as_bytes());
+
+        Ok(Commitment::from(salted_commitment))
+    }
+
+    /// Splits the salt off the prover's commitment, recomputes the expected digest using the
+    /// prover's secret and random number, then compares both digests in constant time.
+    ///
+    /// A commitment whose length differs from `COMMITMENT_LEN` is rejected with a
+    /// [`HashCommitmentError::LengthMismatch`](crate::HashCommitmentError) before any hashing.
+    pub fn verify(&self, com: &Commitment, s: &T, r: &[u8]) -> Result<bool> {
+        check_commitment_len(com, Self::COMMITMENT_LEN)?;
+
+        let (salt, digest) = com.as_bytes().split_at(SALT_LEN);
+
+        DigestCommitment::<T, D>::with_domain(s, r, salt).verify(digest, s, r)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SHA256SaltedCommitment, SALT_LEN};
+
+    #[test]
+    fn it_salts_each_commitment() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+        let fake: [u8; 4] = [66, 68, 66, 68];
+
+        // Commit phase.
+        let party = SHA256SaltedCommitment::new(&s, &r);
+        let commit = party.commit().unwrap();
+        let other_commit = party.commit().unwrap();
+
+        assert_eq!(commit.len(), SALT_LEN + 32);
+        assert_ne!(commit, other_commit);
+
+        // Verification phase.
+        assert!(party.verify(&commit, &s, &r).unwrap());
+        assert!(party.verify(&other_commit, &s, &r).unwrap());
+        assert!(!party.verify(&commit, &fake, &r).unwrap())
+    }
+}
@@ -0,0 +1,292 @@
+use crate::{encode_len, length_prefix, Result};
+use alloc::vec::Vec;
+use bincode::config::{Configuration, Fixint, LittleEndian, NoLimit};
+use bincode::enc::write::{SizeWriter, Writer};
+use bincode::error::EncodeError;
+use serde::Serialize;
+use sha2::Digest;
+
+/// A serialization backend used to encode the secret to a byte array before it is hashed.
+///
+/// The encoding of the secret is part of the commitment's preimage, so the same secret will
+/// produce different commitments under different serializers. Both parties must therefore agree
+/// on the serializer to use.
+pub trait Serializer {
+    /// Encodes the given value to a byte array.
+    fn serialize<T: Serialize + ?Sized>(value: &T) -> Result<Vec<u8>>;
+
+    /// Appends the encoding of the given value to the buffer.
+    ///
+    /// The default implementation encodes the value to an intermediate buffer first, serializers
+    /// able to write to an existing buffer can override it to avoid this allocation.
+    fn serialize_into<T: Serialize + ?Sized>(value: &T, buffer: &mut Vec<u8>) -> Result<()> {
+        buffer.extend_from_slice(&Self::serialize(value)?);
+
+        Ok(())
+    }
+
+    /// Returns the length in bytes of the encoding of the given value.
+    ///
+    /// The default implementation encodes the value to an intermediate buffer, serializers able
+    /// to measure their output can override it to avoid this allocation.
+    fn serialized_len<T: Serialize + ?Sized>(value: &T) -> Result<usize> {
+        Ok(Self::serialize(value)?.len())
+    }
+
+    /// Feeds the encoding of the given value to the hasher, prefixed with its length.
+    ///
+    /// The default implementation encodes the value to an intermediate buffer first, serializers
+    /// able to stream their output can override it to avoid this allocation.
+    fn update_digest<T: Serialize + ?Sized, D: Digest>(value: &T, hasher: &mut D) -> Result<()> {
+        let encoded_value = Self::serialize(value)?;
+
+        hasher.update(length_prefix(&encoded_value));
+        hasher.update(encoded_value);
+
+        Ok(())
+    }
+}
+
+/// The bincode configuration used by [`BincodeSerializer`], identically when committing and
+/// verifying.
+///
+/// Integers are encoded little-endian with a fixed size and no size limit, which is the legacy
+/// configuration of bincode producing the same encoding as bincode 1.x. Since the encoding is
+/// part of the commitment's preimage, this configuration is pinned: changing it would change
+/// every commitment forged from a serialized secret.
+pub const BINCODE_CONFIG: Configuration<LittleEndian, Fixint, NoLimit> =
+    bincode::config::standard()
+        .with_little_endian()
+        .with_fixed_int_encoding()
+        .with_no_limit();
+
+/// A serializer using bincode, which produces compact binary encodings.
+///
+/// The secret is encoded using [`BINCODE_CONFIG`], so that the commitments remain stable across
+/// versions of this crate. This is the default serializer of the commitment schemes.
+pub struct BincodeSerializer;
+
+impl Serializer for BincodeSerializer {
+    fn serialize<T: Serialize + ?Sized>(value: &T) -> Result<Vec<u8>> {
+        Ok(bincode::serde::encode_to_vec(value, BINCODE_CONFIG)?)
+    }
+
+    fn serialize_into<T: Serialize + ?Sized>(value: &T, buffer: &mut Vec<u8>) -> Result<()> {
+        bincode::serde::encode_into_writer(value, VecWriter(buffer), BINCODE_CONFIG)?;
+
+        Ok(())
+    }
+
+    /// Measures the encoding of the value without buffering it.
+    fn serialized_len<T: Serialize + ?Sized>(value: &T) -> Result<usize> {
+        let mut size_writer = SizeWriter::default();
+        bincode::serde::encode_into_writer(value, &mut size_writer, BINCODE_CONFIG)?;
+
+        Ok(size_writer.bytes_written)
+    }
+
+    /// Streams the encoding of the value to the hasher without buffering it.
+    ///
+    /// The value is encoded twice: once to compute the length prefix, then once more to feed
+    /// the hasher.
+    fn update_digest<T: Serialize + ?Sized, D: Digest>(value: &T, hasher: &mut D) -> Result<()> {
+        hasher.update(encode_len(Self::serialized_len(value)?));
+        bincode::serde::encode_into_writer(value, DigestWriter(hasher), BINCODE_CONFIG)?;
+
+        Ok(())
+    }
+}
+
+/// A bincode writer appending the bytes it receives to a buffer.
+struct VecWriter<'a>(&'a mut Vec<u8>);
+
+impl<'a> Writer for VecWriter<'a> {
+    fn write(&mut self, bytes: &[u8]) -> core::result::Result<(), EncodeError> {
+        self.0.extend_from_slice(bytes);
+
+        Ok(())
+    }
+}
+
+/// A bincode writer feeding the bytes it receives to a hasher.
+struct DigestWriter<'a, D: Digest>(&'a mut D);
+
+impl<'a, D: Digest> Writer for DigestWriter<'a, D> {
+    fn write(&mut self, bytes: &[u8]) -> core::result::Result<(), EncodeError> {
+        self.0.update(bytes);
+
+        Ok(())
+    }
+}
+
+/// A serializer using JSON, which makes the commitments easy to reproduce from other languages.
+///
+/// This serializer is only available when the `json` feature is enabled.
+#[cfg(feature = "json")]
+pub struct JsonSerializer;
+
+#[cfg(feature = "json")]
+impl Serializer for JsonSerializer {
+    fn serialize<T: Serialize + ?Sized>(value: &T) -> Result<Vec<u8>> {
+        Ok(serde_json::to_vec(value)?)
+    }
+}
+
+/// Encodes the given JSON value with the keys of each object sorted, recursively.
+///
+/// The keys of a [`serde_json::Map`] are only sorted when the `preserve_order` feature of
+/// serde_json is disabled, which another crate of the dependency graph may enable, so they are
+/// sorted here whatever the features.
+#[cfg(feature = "json")]
+pub(crate) fn canonical_json(value: &serde_json::Value) -> Result<Vec<u8>> {
+    Ok(serde_json::to_vec(&CanonicalJson(value))?)
+}
+
+/// A JSON value serialized with the keys of each object sorted.
+#[cfg(feature = "json")]
+struct CanonicalJson<'a>(&'a serde_json::Value);
+
+#[cfg(feature = "json")]
+impl<'a> Serialize for CanonicalJson<'a> {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> core::result::Result<S::Ok, S::Error> {
+        use serde::ser::{SerializeMap, SerializeSeq};
+        use serde_json::Value;
+
+        match self.0 {
+            Value::Array(values) => {
+                let mut seq = serializer.serialize_seq(Some(values.len()))?;
+                for value in values {
+                    seq.serialize_element(&CanonicalJson(value))?;
+                }
+                seq.end()
+            }
+            Value::Object(object) => {
+                let mut entries: Vec<_> = object.iter().collect();
+                entries.sort_unstable_by_key(|&(key, _)| key);
+
+                let mut map = serializer.serialize_map(Some(entries.len()))?;
+                for (key, value) in entries {
+                    map.serialize_entry(key, &CanonicalJson(value))?;
+                }
+                map.end()
+            }
+            value => value.serialize(serializer),
+        }
+    }
+}
+
+/// A serializer using postcard, whose compact encoding suits embedded targets.
+///
+/// Integers are encoded as varints, so the commitments forged under postcard differ from the
+/// ones forged under [`BincodeSerializer`] for the same secret.
+///
+/// This serializer is only available when the `postcard` feature is enabled.
+#[cfg(feature = "postcard")]
+pub struct PostcardSerializer;
+
+#[cfg(feature = "postcard")]
+impl Serializer for PostcardSerializer {
+    fn serialize<T: Serialize + ?Sized>(value: &T) -> Result<Vec<u8>> {
+        Ok(postcard::to_allocvec(value)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BincodeSerializer, Serializer};
+
+    #[test]
+    fn it_serializes_with_bincode() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let v: Vec<u8> = s.to_vec();
+
+        assert_eq!(BincodeSerializer::serialize(&s).unwrap(), s);
+        assert_eq!(
+            BincodeSerializer::serialize(&v).unwrap(),
+            [4, 0, 0, 0, 0, 0, 0, 0, 52, 50, 52, 50]
+        )
+    }
+
+    /// The streamed encoding must hash to the same digest as the length-prefixed buffer.
+    #[test]
+    fn it_streams_bincode_to_a_digest() {
+        use sha2::{Digest, Sha256};
+
+        let v: Vec<u8> = vec![52, 50, 52, 50]; // 4242 in string format.
+
+        let mut hasher = Sha256::new();
+        BincodeSerializer::update_digest(&v, &mut hasher).unwrap();
+
+        let expected = Sha256::new()
+            .chain_update(12u64.to_be_bytes())
+            .chain_update([4, 0, 0, 0, 0, 0, 0, 0, 52, 50, 52, 50])
+            .finalize();
+
+        assert_eq!(hasher.finalize(), expected)
+    }
+
+    /// Commitments must not depend on how the bincode configuration is built, as long as it
+    /// encodes integers the same way.
+    #[test]
+    fn it_pins_the_bincode_configuration() {
+        use super::BINCODE_CONFIG;
+        use crate::{HashCommitmentScheme, SHA256Commitment};
+        use sha2::{Digest, Sha256};
+
+        let s: Vec<u64> = vec![4242, 2424];
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let pinned = bincode::serde::encode_to_vec(&s, BINCODE_CONFIG).unwrap();
+        let legacy = bincode::serde::encode_to_vec(&s, bincode::config::legacy()).unwrap();
+        let explicit = bincode::serde::encode_to_vec(
+            &s,
+            bincode::config::standard()
+                .with_fixed_int_encoding()
+                .with_little_endian(),
+        )
+        .unwrap();
+
+        assert_eq!(pinned, legacy);
+        assert_eq!(pinned, explicit);
+        assert_eq!(BincodeSerializer::serialize(&s).unwrap(), pinned);
+
+        let expected = Sha256::new()
+            .chain_update((explicit.len() as u64).to_be_bytes())
+            .chain_update(&explicit)
+            .chain_update((r.len() as u64).to_be_bytes())
+            .chain_update(r)
+            .finalize();
+        let party = SHA256Commitment::new(&s, &r);
+        let commit = party.commit().unwrap();
+
+        assert_eq!(commit.as_bytes(), expected.as_slice());
+        assert!(party.verify(&commit, &s, &r).unwrap())
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn it_serializes_with_json() {
+        use super::JsonSerializer;
+
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+
+        assert_eq!(JsonSerializer::serialize(&s).unwrap(), b"[52,50,52,50]")
+    }
+
+    #[cfg(feature = "postcard")]
+    #[test]
+    fn it_serializes_with_postcard() {
+        use super::PostcardSerializer;
+
+        let v: Vec<u8> = vec![52, 50, 52, 50]; // 4242 in string format.
+
+        assert_eq!(
+            PostcardSerializer::serialize(&v).unwrap(),
+            [4, 52, 50, 52, 50]
+        );
+        assert_eq!(PostcardSerializer::serialize(&300u64).unwrap(), [172, 2])
+    }
+}
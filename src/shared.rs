@@ -0,0 +1,140 @@
+use crate::{
+    Commitment, DigestCommitment, HashCommitmentScheme, Result, DEFAULT_MIN_RANDOMNESS_LEN,
+};
+use alloc::rc::Rc;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use core::ops::Deref;
+use serde::Serialize;
+use sha2::digest::typenum::Unsigned;
+use sha2::digest::Output;
+use sha2::{Digest, Sha256};
+
+/// An implementation of the Hash Commitment Scheme which holds the party's secret behind a
+/// shared pointer, such as an [`Arc`] or an [`Rc`].
+///
+/// Unlike [`DigestCommitment`], this structure does not borrow the secret, so it can be moved
+/// across tasks without lifetime constraints, and unlike
+/// [`OwnedDigestCommitment`](crate::OwnedDigestCommitment) it does not need the secret to be
+/// cloned. The commitment is forged by borrowing the secret through the pointer, using the same
+/// framing as [`DigestCommitment`].
+pub struct SharedDigestCommitment<P: Deref, D: Digest>
+where
+    P::Target: Serialize + Sized,
+{
+    s: P,
+    r: Vec<u8>,
+    min_randomness: usize,
+    _digest: PhantomData<D>,
+}
+
+/// An implementation of the Hash Commitment Scheme using the SHA256 hash function, which holds
+/// the party's secret behind a shared pointer.
+pub type SharedSHA256Commitment<P> = SharedDigestCommitment<P, Sha256>;
+
+impl<P: Deref, D: Digest> SharedDigestCommitment<P, D>
+where
+    P::Target: Serialize + Sized,
+{
+    /// Creates a new party for the Commitment Scheme using a pointer to its secret and its
+    /// random number.
+    pub fn new(s: P, r: Vec<u8>) -> SharedDigestCommitment<P, D> {
+        SharedDigestCommitment {
+            s,
+            r,
+            min_randomness: DEFAULT_MIN_RANDOMNESS_LEN,
+            _digest: PhantomData,
+        }
+    }
+
+    /// Returns the random number used by the party to forge its commitment.
+    pub fn randomness(&self) -> &[u8] {
+        &self.r
+    }
+
+    /// Sets the minimum length in bytes of the random number accepted at commit time, which
+    /// defaults to [`DEFAULT_MIN_RANDOMNESS_LEN`](crate::DEFAULT_MIN_RANDOMNESS_LEN).
+    pub fn with_min_randomness(mut self, min_randomness: usize) -> Self {
+        self.min_randomness = min_randomness;
+        self
+    }
+
+    /// Creates a borrowing party over the shared secret.
+    fn borrowed(&self) -> DigestCommitment<'_, P::Target, D> {
+        DigestCommitment::new(&*self.s, &self.r).with_min_randomness(self.min_randomness)
+    }
+}
+
+impl<T: Serialize, D: Digest> SharedDigestCommitment<Arc<T>, D> {
+    /// Creates a new party for the Commitment Scheme using its secret shared through an [`Arc`].
+    pub fn from_arc(s: Arc<T>, r: Vec<u8>) -> SharedDigestCommitment<Arc<T>, D> {
+        SharedDigestCommitment::new(s, r)
+    }
+}
+
+impl<T: Serialize, D: Digest> SharedDigestCommitment<Rc<T>, D> {
+    /// Creates a new party for the Commitment Scheme using its secret shared through an [`Rc`].
+    pub fn from_rc(s: Rc<T>, r: Vec<u8>) -> SharedDigestCommitment<Rc<T>, D> {
+        SharedDigestCommitment::new(s, r)
+    }
+}
+
+impl<P: Deref, D: Digest> HashCommitmentScheme<P::Target> for SharedDigestCommitment<P, D>
+where
+    P::Target: Serialize + Sized,
+{
+    const COMMITMENT_LEN: usize = D::OutputSize::USIZE;
+
+    type Output = Output<D>;
+
+    /// Creates the bytes of the commitment used during the commit phase.
+    ///
+    /// Returns a [`HashCommitmentError::InsufficientRandomness`](crate::HashCommitmentError)
+    /// if the random number is too short to hide the secret.
+    fn commit_output(&self) -> Result<Self::Output> {
+        self.borrowed().commit_output()
+    }
+
+    fn recommit(&self, s: &P::Target, r: &[u8]) -> Result<Commitment> {
+        self.borrowed().recommit(s, r)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SharedSHA256Commitment;
+    use crate::{HashCommitmentScheme, SHA256Commitment};
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    #[test]
+    fn it_commits_to_a_secret_behind_an_arc() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let shared_s = Arc::new(s);
+        let party = SharedSHA256Commitment::from_arc(Arc::clone(&shared_s), r.to_vec());
+
+        let commit = std::thread::spawn(move || party.commit().unwrap())
+            .join()
+            .unwrap();
+
+        assert_eq!(commit, SHA256Commitment::new(&s, &r).commit().unwrap());
+        assert!(SharedSHA256Commitment::from_arc(shared_s, r.to_vec())
+            .verify(&commit, &s, &r)
+            .unwrap())
+    }
+
+    #[test]
+    fn it_commits_to_a_secret_behind_an_rc() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let party = SharedSHA256Commitment::from_rc(Rc::new(s), r.to_vec());
+        let commit = party.commit().unwrap();
+
+        assert_eq!(commit, SHA256Commitment::new(&s, &r).commit().unwrap());
+        assert!(party.verify(&commit, &s, &r).unwrap())
+    }
+}
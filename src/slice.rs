@@ -0,0 +1,195 @@
+//! A flat commitment over a slice of secrets.
+//!
+//! Each leaf is the SHA256 commitment of a secret and its random number, and the root is the
+//! SHA256 hash of the concatenation of all the leaves. A single secret can later be opened by
+//! revealing it along with the other leaves. The proof grows linearly with the number of
+//! secrets, which is simpler than a [`MerkleCommitment`](crate::merkle::MerkleCommitment) and
+//! as compact when the slice is small.
+
+use crate::{Commitment, HashCommitmentScheme, Result, SHA256Commitment};
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use subtle::ConstantTimeEq;
+
+/// A commitment to a slice of secrets, made of the commitment of each of them.
+pub struct SliceCommitment<T: Serialize> {
+    leaves: Vec<Commitment>,
+    root: Commitment,
+    _secret: PhantomData<T>,
+}
+
+impl<T: Serialize + Sync> SliceCommitment<T> {
+    /// Commits to the given pairs of secret and random number.
+    pub fn new(leaves: &[(&T, &[u8])]) -> Result<SliceCommitment<T>> {
+        let leaves = SHA256Commitment::batch_commit(leaves)?;
+        let root = hash_leaves(&leaves);
+
+        Ok(SliceCommitment {
+            leaves,
+            root,
+            _secret: PhantomData,
+        })
+    }
+}
+
+impl<T: Serialize> SliceCommitment<T> {
+    /// Returns the root commitment, which commits to all the secrets.
+    pub fn root(&self) -> &Commitment {
+        &self.root
+    }
+
+    /// Returns the number of secrets committed to.
+    pub fn len(&self) -> usize {
+        self.leaves.len()
+    }
+
+    /// Returns true if the slice of secrets committed to is empty.
+    pub fn is_empty(&self) -> bool {
+        self.leaves.is_empty()
+    }
+
+    /// Returns the leaves of all the other secrets, which prove that the secret at the given
+    /// index is part of the slice, or `None` if the index is out of bounds.
+    pub fn open(&self, index: usize) -> Option<Vec<Commitment>> {
+        if index >= self.len() {
+            return None;
+        }
+
+        let mut siblings = self.leaves.clone();
+        siblings.remove(index);
+
+        Some(siblings)
+    }
+
+    /// Verifies that the secret s and random number r open the element at the given index of
+    /// the slice whose root is given, using the sibling leaves provided by the prover.
+    ///
+    /// The root is recomputed by inserting the leaf commitment among its siblings, then
+    /// compared with the expected one in constant time. Every sibling must be a SHA256
+    /// commitment, so that the leaf can not be shifted to another index.
+    pub fn verify_element(
+        root: &Commitment,
+        index: usize,
+        s: &T,
+        r: &[u8],
+        siblings: &[Commitment],
+    ) -> Result<bool> {
+        if index > siblings.len()
+            || siblings
+                .iter()
+                .any(|sibling| sibling.len() != SHA256Commitment::<T>::COMMITMENT_LEN)
+        {
+            return Ok(false);
+        }
+
+        let leaf = SHA256Commitment::new(s, r).commit()?;
+        let mut leaves = siblings.to_vec();
+        leaves.insert(index, leaf);
+
+        Ok(hash_leaves(&leaves)
+            .as_bytes()
+            .ct_eq(root.as_bytes())
+            .into())
+    }
+}
+
+/// Computes the root of the slice from its leaves.
+fn hash_leaves(leaves: &[Commitment]) -> Commitment {
+    let hash = leaves
+        .iter()
+        .fold(Sha256::new(), |hasher, leaf| hasher.chain_update(leaf))
+        .finalize();
+
+    Commitment::from(hash.to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SliceCommitment;
+    use crate::Commitment;
+
+    fn leaves() -> (Vec<[u8; 4]>, Vec<[u8; 16]>) {
+        let secrets = (0..3).map(|i| [i, 50, 52, 50]).collect();
+        let randoms = (0..3).map(|i| [i; 16]).collect();
+
+        (secrets, randoms)
+    }
+
+    fn items<'a>(secrets: &'a [[u8; 4]], randoms: &'a [[u8; 16]]) -> Vec<(&'a [u8; 4], &'a [u8])> {
+        secrets
+            .iter()
+            .zip(randoms.iter())
+            .map(|(s, r)| (s, r.as_slice()))
+            .collect()
+    }
+
+    #[test]
+    fn it_opens_every_element() {
+        let (secrets, randoms) = leaves();
+        let items = items(&secrets, &randoms);
+
+        let slice = SliceCommitment::new(&items).unwrap();
+
+        assert_eq!(slice.len(), 3);
+        for (index, (s, r)) in items.iter().enumerate() {
+            let siblings = slice.open(index).unwrap();
+
+            assert_eq!(siblings.len(), 2);
+            assert!(
+                SliceCommitment::verify_element(slice.root(), index, *s, r, &siblings).unwrap()
+            );
+        }
+        assert!(slice.open(3).is_none())
+    }
+
+    #[test]
+    fn it_rejects_a_tampered_sibling() {
+        let (secrets, randoms) = leaves();
+        let items = items(&secrets, &randoms);
+
+        let slice = SliceCommitment::new(&items).unwrap();
+        let siblings = slice.open(1).unwrap();
+        let mut tampered_siblings = siblings.clone();
+        tampered_siblings[0] = Commitment::from(vec![0u8; 32]);
+
+        let verification = SliceCommitment::verify_element(
+            slice.root(),
+            1,
+            &secrets[1],
+            &randoms[1],
+            &tampered_siblings,
+        );
+        let wrong_index =
+            SliceCommitment::verify_element(slice.root(), 2, &secrets[1], &randoms[1], &siblings);
+
+        assert!(!verification.unwrap());
+        assert!(!wrong_index.unwrap())
+    }
+
+    /// Here, the first sibling is split in two halves, which keeps the concatenation of the
+    /// leaves unchanged but shifts the index of the opened leaf.
+    #[test]
+    fn it_rejects_siblings_of_the_wrong_length() {
+        let (secrets, randoms) = leaves();
+        let items = items(&secrets, &randoms);
+
+        let slice = SliceCommitment::new(&items).unwrap();
+        let siblings = slice.open(1).unwrap();
+        let split_siblings = vec![
+            Commitment::from(&siblings[0].as_bytes()[..16]),
+            Commitment::from(&siblings[0].as_bytes()[16..]),
+            siblings[1].clone(),
+        ];
+
+        assert!(!SliceCommitment::verify_element(
+            slice.root(),
+            2,
+            &secrets[1],
+            &randoms[1],
+            &split_siblings
+        )
+        .unwrap())
+    }
+}
@@ -0,0 +1,80 @@
+//! Reusable invariants of the Hash Commitment Schemes, to be driven by property-based tests.
+//!
+//! Each helper runs a commit and verify cycle and checks the outcome expected by one of the
+//! properties of the scheme. They return a [`Result`] rather than panicking, so that a failing
+//! invariant can be reported through the `?` operator of a proptest case.
+//!
+//! This module is only available when the `testing` feature is enabled, which enables the `std`
+//! feature as well so that the errors of the helpers can be propagated with `?`.
+
+use crate::serializer::{BincodeSerializer, Serializer};
+use crate::{HashCommitmentError, HashCommitmentScheme, Result, SHA256Commitment};
+use serde::Serialize;
+
+/// Asserts that the commitment to the secret s with the random number r is opened by the same
+/// secret and random number.
+///
+/// Returns a [`HashCommitmentError::InvalidOpening`] if the opening does not hold.
+pub fn assert_commit_verify_roundtrip<T: Serialize>(s: &T, r: &[u8]) -> Result<()> {
+    let party = SHA256Commitment::new(s, r);
+    let commit = party.commit()?;
+
+    if !party.verify(&commit, s, r)? {
+        return Err(HashCommitmentError::InvalidOpening);
+    }
+
+    Ok(())
+}
+
+/// Asserts that the commitment to the secret s with the random number r is not opened by
+/// another secret and random number.
+///
+/// An other opening which encodes to the same secret and random number is not a violation and
+/// is accepted. Returns a [`HashCommitmentError::BindingViolation`] if the other opening holds.
+pub fn assert_binding_violation_detected<T: Serialize>(
+    s: &T,
+    r: &[u8],
+    other_s: &T,
+    other_r: &[u8],
+) -> Result<()> {
+    if r == other_r && BincodeSerializer::serialize(s)? == BincodeSerializer::serialize(other_s)? {
+        return Ok(());
+    }
+
+    let party = SHA256Commitment::new(s, r);
+    let commit = party.commit()?;
+
+    if party.verify(&commit, other_s, other_r)? {
+        return Err(HashCommitmentError::BindingViolation);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{assert_binding_violation_detected, assert_commit_verify_roundtrip};
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn it_verifies_every_opening(
+            s in proptest::collection::vec(any::<u8>(), 0..64),
+            r in proptest::collection::vec(any::<u8>(), 16..64),
+        ) {
+            assert_commit_verify_roundtrip(&s, &r)?;
+        }
+
+        #[test]
+        fn it_detects_every_binding_violation(
+            s in proptest::collection::vec(any::<u8>(), 0..64),
+            r in proptest::collection::vec(any::<u8>(), 16..64),
+            other_s in proptest::collection::vec(any::<u8>(), 0..64),
+            other_r in proptest::collection::vec(any::<u8>(), 16..64),
+        ) {
+            assert_binding_violation_detected(&s, &r, &other_s, &other_r)?;
+            assert_binding_violation_detected(&s, &r, &other_s, &r)?;
+            assert_binding_violation_detected(&s, &r, &s, &other_r)?;
+        }
+    }
+}
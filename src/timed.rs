@@ -0,0 +1,167 @@
+use crate::{
+    tagged_domain, Commitment, DigestCommitment, HashCommitmentScheme, Opening, Result,
+    DEFAULT_MIN_RANDOMNESS_LEN, TIMED_DOMAIN,
+};
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use serde::Serialize;
+use sha2::digest::typenum::Unsigned;
+use sha2::digest::Output;
+use sha2::{Digest, Sha256};
+
+/// An implementation of the Hash Commitment Scheme which binds a deadline to the commitment, so
+/// that a late opening can be rejected.
+///
+/// The deadline is a unix timestamp in seconds, bound into the hash as the domain-separation
+/// tag of a [`DigestCommitment`], encoded as a big-endian u64 after a fixed prefix. Changing the
+/// deadline therefore invalidates the commitment, and a timed commitment never collides with an
+/// indexed one for the same number.
+pub struct TimedCommitment<'a, T: 'a + Serialize, D: Digest> {
+    s: &'a T,
+    r: &'a [u8],
+    expires_at: u64,
+    min_randomness: usize,
+    _digest: PhantomData<D>,
+}
+
+/// An implementation of the timed Hash Commitment Scheme using the SHA256 hash function.
+pub type SHA256TimedCommitment<'a, T> = TimedCommitment<'a, T, Sha256>;
+
+impl<'a, T: 'a + Serialize, D: Digest> TimedCommitment<'a, T, D> {
+    /// Creates a new party for the Commitment Scheme using its secret, random number and the
+    /// unix timestamp, in seconds, after which the commitment can no longer be opened.
+    pub fn new(s: &'a T, r: &'a [u8], expires_at: u64) -> TimedCommitment<'a, T, D> {
+        TimedCommitment {
+            s,
+            r,
+            expires_at,
+            min_randomness: DEFAULT_MIN_RANDOMNESS_LEN,
+            _digest: PhantomData,
+        }
+    }
+
+    /// Sets the minimum length in bytes of the random number accepted at commit time, which
+    /// defaults to [`DEFAULT_MIN_RANDOMNESS_LEN`](crate::DEFAULT_MIN_RANDOMNESS_LEN).
+    pub fn with_min_randomness(mut self, min_randomness: usize) -> Self {
+        self.min_randomness = min_randomness;
+        self
+    }
+
+    /// Returns the unix timestamp, in seconds, after which the commitment expires.
+    pub fn expires_at(&self) -> u64 {
+        self.expires_at
+    }
+
+    /// Reveals the party's secret and random number, to be sent to the verifier during the open
+    /// phase.
+    pub fn open(&self) -> Opening<T>
+    where
+        T: Clone,
+    {
+        Opening::new(self.s.clone(), self.r.to_vec())
+    }
+
+    /// Verifies that the secret s and random number r open the commitment bound to the given
+    /// deadline, at the unix timestamp now.
+    ///
+    /// An opening revealed after the deadline never holds, even if it matches the commitment.
+    pub fn verify_timed(
+        com: &Commitment,
+        s: &T,
+        r: &[u8],
+        expires_at: u64,
+        now: u64,
+    ) -> Result<bool> {
+        if now > expires_at {
+            return Ok(false);
+        }
+
+        TimedCommitment::<T, D>::new(s, r, expires_at).verify(com, s, r)
+    }
+
+    /// Returns the domain-separation tag binding the deadline into the commitment.
+    fn domain(&self) -> Vec<u8> {
+        tagged_domain(TIMED_DOMAIN, self.expires_at)
+    }
+}
+
+impl<'a, T: 'a + Serialize, D: Digest> HashCommitmentScheme<T> for TimedCommitment<'a, T, D> {
+    const COMMITMENT_LEN: usize = D::OutputSize::USIZE;
+
+    type Output = Output<D>;
+
+    /// Creates the bytes of the commitment used during the commit phase.
+    ///
+    /// Returns a [`HashCommitmentError::InsufficientRandomness`](crate::HashCommitmentError)
+    /// if the random number is too short to hide the secret.
+    fn commit_output(&self) -> Result<Self::Output> {
+        DigestCommitment::<T, D>::with_domain(self.s, self.r, &self.domain())
+            .with_min_randomness(self.min_randomness)
+            .commit_output()
+    }
+
+    /// Forges the commitment to the secret s and random number r, bound to the party's
+    /// deadline. The deadline itself is not checked, see `verify_timed`.
+    fn recommit(&self, s: &T, r: &[u8]) -> Result<Commitment> {
+        DigestCommitment::<T, D>::with_domain(s, r, &self.domain()).recommit(s, r)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SHA256TimedCommitment;
+    use crate::{HashCommitmentScheme, SHA256Commitment};
+
+    const EXPIRES_AT: u64 = 1_700_000_000;
+
+    #[test]
+    fn it_verifies_before_expiry() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        // Commit phase.
+        let commit = SHA256TimedCommitment::new(&s, &r, EXPIRES_AT)
+            .commit()
+            .unwrap();
+
+        // Verification phase.
+        let verify = |expires_at, now| {
+            SHA256TimedCommitment::verify_timed(&commit, &s, &r, expires_at, now).unwrap()
+        };
+
+        assert!(verify(EXPIRES_AT, EXPIRES_AT - 60));
+        assert!(verify(EXPIRES_AT, EXPIRES_AT));
+        assert!(!verify(EXPIRES_AT + 60, EXPIRES_AT))
+    }
+
+    #[test]
+    fn it_rejects_an_opening_after_expiry() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let party = SHA256TimedCommitment::new(&s, &r, EXPIRES_AT);
+        let commit = party.commit().unwrap();
+
+        assert!(party.verify(&commit, &s, &r).unwrap());
+        assert!(
+            !SHA256TimedCommitment::verify_timed(&commit, &s, &r, EXPIRES_AT, EXPIRES_AT + 1)
+                .unwrap()
+        )
+    }
+
+    #[test]
+    fn it_never_collides_with_an_indexed_commitment() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let commit = SHA256TimedCommitment::new(&s, &r, 1).commit().unwrap();
+
+        assert_ne!(commit, SHA256Commitment::commit_indexed(1, &s, &r).unwrap());
+        assert_ne!(
+            commit,
+            SHA256Commitment::with_domain(&s, &r, &1u64.to_be_bytes())
+                .commit()
+                .unwrap()
+        )
+    }
+}
@@ -0,0 +1,144 @@
+//! A vector commitment whose positions can be opened independently.
+//!
+//! Each position of the vector is committed to with its own random number, and its index is
+//! bound into the commitment as a domain-separation tag. A single position can then be revealed
+//! without revealing the others, and without any Merkle proof, at the cost of publishing one
+//! commitment per position.
+
+use crate::{
+    tagged_domain, Commitment, HashCommitmentScheme, Opening, Result, SHA256Commitment,
+    INDEX_DOMAIN,
+};
+use alloc::vec::Vec;
+use serde::Serialize;
+
+/// A commitment to a fixed-size vector of secrets, made of one SHA256 commitment per position.
+///
+/// The commitment of the secret s at index i with the random number r is the one forged by
+/// [`SHA256Commitment::commit_indexed`], the index being encoded as a big-endian u64 after a
+/// fixed prefix and used as the domain of a [`SHA256Commitment`].
+/// Binding the index prevents the opening of one position from being accepted for another one.
+pub struct VectorCommitment<'a, T: Serialize> {
+    leaves: Vec<(&'a T, &'a [u8])>,
+    commitments: Vec<Commitment>,
+}
+
+impl<'a, T: Serialize> VectorCommitment<'a, T> {
+    /// Commits to each of the given pairs of secret and random number, in order.
+    pub fn new(leaves: &[(&'a T, &'a [u8])]) -> Result<VectorCommitment<'a, T>> {
+        let commitments = leaves
+            .iter()
+            .enumerate()
+            .map(|(index, &(s, r))| SHA256Commitment::with_domain(s, r, &index_tag(index)).commit())
+            .collect::<Result<_>>()?;
+
+        Ok(VectorCommitment {
+            leaves: leaves.to_vec(),
+            commitments,
+        })
+    }
+
+    /// Returns the commitments of each position, to be sent to the verifier.
+    pub fn commitments(&self) -> &[Commitment] {
+        &self.commitments
+    }
+
+    /// Returns the number of positions committed to.
+    pub fn len(&self) -> usize {
+        self.commitments.len()
+    }
+
+    /// Returns true if the vector does not commit to any secret.
+    pub fn is_empty(&self) -> bool {
+        self.commitments.is_empty()
+    }
+
+    /// Reveals the secret and random number of the given position, or `None` if the index is
+    /// out of bounds.
+    pub fn open(&self, index: usize) -> Option<Opening<T>>
+    where
+        T: Clone,
+    {
+        self.leaves
+            .get(index)
+            .map(|&(s, r)| Opening::new(s.clone(), r.to_vec()))
+    }
+
+    /// Verifies that the secret s and random number r open the commitment of the given
+    /// position.
+    ///
+    /// An index which is out of bounds never holds.
+    pub fn verify_position(&self, index: usize, s: &T, r: &[u8]) -> Result<bool> {
+        match self.commitments.get(index) {
+            Some(com) => SHA256Commitment::with_domain(s, r, &index_tag(index)).verify(com, s, r),
+            None => Ok(false),
+        }
+    }
+}
+
+/// Encodes the index of a position as the domain-separation tag of its commitment.
+fn index_tag(index: usize) -> Vec<u8> {
+    tagged_domain(INDEX_DOMAIN, index as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::VectorCommitment;
+
+    fn leaves() -> (Vec<[u8; 4]>, Vec<[u8; 16]>) {
+        let secrets = (0..4).map(|i| [i, 50, 52, 50]).collect();
+        let randoms = (0..4).map(|i| [i; 16]).collect();
+
+        (secrets, randoms)
+    }
+
+    #[test]
+    fn it_opens_every_position() {
+        let (secrets, randoms) = leaves();
+        let items: Vec<(&[u8; 4], &[u8])> = secrets
+            .iter()
+            .zip(randoms.iter())
+            .map(|(s, r)| (s, r.as_slice()))
+            .collect();
+
+        let vector = VectorCommitment::new(&items).unwrap();
+
+        assert_eq!(vector.len(), 4);
+        assert_eq!(vector.commitments().len(), 4);
+        for index in 0..vector.len() {
+            let opening = vector.open(index).unwrap();
+
+            assert!(vector
+                .verify_position(index, opening.secret(), opening.randomness())
+                .unwrap());
+        }
+        assert!(vector.open(4).is_none())
+    }
+
+    /// Here, the openings of two positions are swapped. The index binding makes each of them
+    /// only hold at its own position, even when the same values are committed to twice.
+    #[test]
+    fn it_rejects_an_opening_at_another_position() {
+        let (secrets, randoms) = leaves();
+        let items: Vec<(&[u8; 4], &[u8])> = vec![
+            (&secrets[0], &randoms[0]),
+            (&secrets[1], &randoms[1]),
+            (&secrets[0], &randoms[0]),
+        ];
+
+        let vector = VectorCommitment::new(&items).unwrap();
+        let opening = vector.open(0).unwrap();
+        let other_opening = vector.open(1).unwrap();
+
+        assert_ne!(vector.commitments()[0], vector.commitments()[2]);
+        assert!(!vector
+            .verify_position(1, opening.secret(), opening.randomness())
+            .unwrap());
+        assert!(!vector
+            .verify_position(0, other_opening.secret(), other_opening.randomness())
+            .unwrap());
+        assert!(!vector
+            .verify_position(3, opening.secret(), opening.randomness())
+            .unwrap())
+    }
+}
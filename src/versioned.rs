@@ -0,0 +1,266 @@
+use crate::serializer::{BincodeSerializer, Serializer};
+use crate::{
+    check_commitment_len, check_randomness_len, length_prefix, Commitment, HashCommitmentError,
+    HashCommitmentScheme, Opening, Result, DEFAULT_MIN_RANDOMNESS_LEN,
+};
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use serde::Serialize;
+use sha2::digest::typenum::Unsigned;
+use sha2::{Digest, Sha256};
+use subtle::ConstantTimeEq;
+
+/// The versions of the framing of the commitment preimage.
+///
+/// The version is bound into the hash and written in front of the commitment, so that
+/// commitments forged with an older framing can still be told apart and verified once the
+/// construction evolves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FramingVersion {
+    /// The encoded secret directly followed by the random number, without length prefixes.
+    ///
+    /// Without framing, bytes can move between the secret and the random number without
+    /// changing the preimage, so the commitments forged under this version are only accepted by
+    /// verifiers which opt into it.
+    V1 = 1,
+
+    /// The encoded secret and the random number, each prefixed with its length.
+    V2 = 2,
+}
+
+impl FramingVersion {
+    /// The framing used for new commitments.
+    pub const LATEST: FramingVersion = FramingVersion::V2;
+
+    /// Returns the version tag written in front of the commitments.
+    pub fn tag(self) -> u8 {
+        self as u8
+    }
+}
+
+impl TryFrom<u8> for FramingVersion {
+    type Error = HashCommitmentError;
+
+    /// Parses a version tag, returning a [`HashCommitmentError::UnsupportedVersion`] if the
+    /// version is unknown.
+    fn try_from(tag: u8) -> Result<Self> {
+        match tag {
+            1 => Ok(FramingVersion::V1),
+            2 => Ok(FramingVersion::V2),
+            _ => Err(HashCommitmentError::UnsupportedVersion(tag)),
+        }
+    }
+}
+
+/// An implementation of the Hash Commitment Scheme whose commitments carry the version of the
+/// framing of their preimage.
+///
+/// The commitment is `v || H(v || preimage)`, where v is the one-byte version tag and the
+/// preimage is framed according to that version. The verifier reads the version from the
+/// commitment and recomputes the digest using the matching framing. Since the version is chosen
+/// by the prover, a verifier only accepts its own framing and [`FramingVersion::V2`] by default,
+/// and must opt into [`FramingVersion::V1`] using `with_v1_accepted` to verify older
+/// commitments.
+pub struct VersionedCommitment<'a, T: 'a + Serialize, D: Digest> {
+    s: &'a T,
+    r: &'a [u8],
+    version: FramingVersion,
+    accept_v1: bool,
+    min_randomness: usize,
+    _digest: PhantomData<D>,
+}
+
+/// An implementation of the versioned Hash Commitment Scheme using the SHA256 hash function.
+///
+/// The commitments produced are 33 bytes long.
+pub type SHA256VersionedCommitment<'a, T> = VersionedCommitment<'a, T, Sha256>;
+
+impl<'a, T: 'a + Serialize, D: Digest> VersionedCommitment<'a, T, D> {
+    /// Creates a new party for the Commitment Scheme using its secret and random number, which
+    /// commits using the latest framing.
+    pub fn new(s: &'a T, r: &'a [u8]) -> VersionedCommitment<'a, T, D> {
+        VersionedCommitment {
+            s,
+            r,
+            version: FramingVersion::LATEST,
+            accept_v1: false,
+            min_randomness: DEFAULT_MIN_RANDOMNESS_LEN,
+            _digest: PhantomData,
+        }
+    }
+
+    /// Sets the framing used to forge the commitments, which defaults to
+    /// [`FramingVersion::LATEST`].
+    pub fn with_version(mut self, version: FramingVersion) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// Sets whether the commitments forged under the unframed [`FramingVersion::V1`] are
+    /// accepted at verification time, which they are not by default unless the party commits
+    /// under this version itself.
+    pub fn with_v1_accepted(mut self, accept_v1: bool) -> Self {
+        self.accept_v1 = accept_v1;
+        self
+    }
+
+    /// Returns whether the commitments forged under the given framing are accepted at
+    /// verification time.
+    fn accepts(&self, version: FramingVersion) -> bool {
+        match version {
+            FramingVersion::V1 => self.accept_v1 || self.version == FramingVersion::V1,
+            FramingVersion::V2 => true,
+        }
+    }
+
+    /// Sets the minimum length in bytes of the random number accepted at commit time, which
+    /// defaults to [`DEFAULT_MIN_RANDOMNESS_LEN`](crate::DEFAULT_MIN_RANDOMNESS_LEN).
+    pub fn with_min_randomness(mut self, min_randomness: usize) -> Self {
+        self.min_randomness = min_randomness;
+        self
+    }
+
+    /// Reveals the party's secret and random number, to be sent to the verifier during the open
+    /// phase.
+    pub fn open(&self) -> Opening<T>
+    where
+        T: Clone,
+    {
+        Opening::new(self.s.clone(), self.r.to_vec())
+    }
+
+    /// Forges a commitment given a secret s and a random number r, using the given framing.
+    fn forge_commitment(version: FramingVersion, s: &T, r: &[u8]) -> Result<Vec<u8>> {
+        let binary_encoded_s = BincodeSerializer::serialize(s)?;
+
+        let mut hasher = D::new().chain_update([version.tag()]);
+        match version {
+            FramingVersion::V1 => {
+                hasher.update(&binary_encoded_s);
+                hasher.update(r);
+            }
+            FramingVersion::V2 => {
+                hasher.update(length_prefix(&binary_encoded_s));
+                hasher.update(&binary_encoded_s);
+                hasher.update(length_prefix(r));
+                hasher.update(r);
+            }
+        }
+
+        let mut versioned_commitment = Vec::with_capacity(1 + D::OutputSize::USIZE);
+        versioned_commitment.push(version.tag());
+        versioned_commitment.extend_from_slice(&hasher.finalize());
+
+        Ok(versioned_commitment)
+    }
+}
+
+impl<'a, T: 'a + Serialize, D: Digest> HashCommitmentScheme<T> for VersionedCommitment<'a, T, D> {
+    const COMMITMENT_LEN: usize = 1 + D::OutputSize::USIZE;
+
+    /// The version tag is prepended to the digest, so the commitment is built in a `Vec`.
+    type Output = Vec<u8>;
+
+    /// Creates the bytes of the commitment used during the commit phase, using the party's
+    /// framing.
+    ///
+    /// Returns a [`HashCommitmentError::InsufficientRandomness`] if the random number is too
+    /// short to hide the secret.
+    fn commit_output(&self) -> Result<Self::Output> {
+        check_randomness_len(self.r, self.min_randomness)?;
+
+        Self::forge_commitment(self.version, self.s, self.r)
+    }
+
+    fn recommit(&self, s: &T, r: &[u8]) -> Result<Commitment> {
+        Self::forge_commitment(self.version, s, r).map(Commitment::from)
+    }
+
+    /// Reads the version of the prover's commitment, then recomputes the expected commitment
+    /// using the matching framing and compares both in constant time.
+    ///
+    /// A commitment whose length differs from `COMMITMENT_LEN` is rejected with a
+    /// [`HashCommitmentError::LengthMismatch`], and one whose version is unknown or not accepted
+    /// by the party with a [`HashCommitmentError::UnsupportedVersion`].
+    fn verify<C, R>(&self, com: &C, s: &T, r: &R) -> Result<bool>
+    where
+        C: AsRef<[u8]> + ?Sized,
+        R: AsRef<[u8]> + ?Sized,
+    {
+        check_commitment_len(com, Self::COMMITMENT_LEN)?;
+
+        let com = com.as_ref();
+        let version = FramingVersion::try_from(com[0])?;
+        if !self.accepts(version) {
+            return Err(HashCommitmentError::UnsupportedVersion(version.tag()));
+        }
+        let expected_commitment = Self::forge_commitment(version, s, r.as_ref())?;
+
+        Ok(expected_commitment.ct_eq(com).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FramingVersion, SHA256VersionedCommitment};
+    use crate::{Commitment, HashCommitmentError, HashCommitmentScheme};
+
+    #[test]
+    fn it_verifies_each_framing_under_its_own_version() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        // Commit phase.
+        let v1_party = SHA256VersionedCommitment::new(&s, &r).with_version(FramingVersion::V1);
+        let v2_party = SHA256VersionedCommitment::new(&s, &r).with_v1_accepted(true);
+        let v1_commit = v1_party.commit().unwrap();
+        let v2_commit = v2_party.commit().unwrap();
+
+        assert_eq!(v1_commit.as_bytes()[0], 1);
+        assert_eq!(v2_commit.as_bytes()[0], 2);
+        assert_ne!(v1_commit.as_bytes()[1..], v2_commit.as_bytes()[1..]);
+
+        // Verification phase, the framing is read from the commitment.
+        assert!(v2_party.verify(&v1_commit, &s, &r).unwrap());
+        assert!(v2_party.verify(&v2_commit, &s, &r).unwrap());
+
+        // Relabelling a commitment with another version does not make it hold.
+        let mut relabelled_commit = v1_commit.as_bytes().to_vec();
+        relabelled_commit[0] = 2;
+
+        assert!(!v2_party
+            .verify(&Commitment::from(relabelled_commit), &s, &r)
+            .unwrap())
+    }
+
+    #[test]
+    fn it_rejects_an_unknown_version() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let party = SHA256VersionedCommitment::new(&s, &r);
+        let mut commit = party.commit().unwrap().as_bytes().to_vec();
+        commit[0] = 42;
+
+        assert!(matches!(
+            party.verify(&Commitment::from(commit), &s, &r),
+            Err(HashCommitmentError::UnsupportedVersion(42))
+        ))
+    }
+
+    #[test]
+    fn it_rejects_a_downgrade_to_the_unframed_version_by_default() {
+        let s: [u8; 4] = [52, 50, 52, 50]; // 4242 in string format.
+        let r: [u8; 16] = *b"2424242424242424";
+
+        let v1_party = SHA256VersionedCommitment::new(&s, &r).with_version(FramingVersion::V1);
+        let v1_commit = v1_party.commit().unwrap();
+
+        assert!(v1_party.verify(&v1_commit, &s, &r).unwrap());
+        assert!(matches!(
+            SHA256VersionedCommitment::new(&s, &r).verify(&v1_commit, &s, &r),
+            Err(HashCommitmentError::UnsupportedVersion(1))
+        ))
+    }
+}
@@ -0,0 +1,61 @@
+//! Bindings to use the SHA256 Hash Commitment Scheme from JavaScript.
+//!
+//! The functions operate on raw byte arrays using [`SHA256BytesCommitment`], so that the
+//! commitments do not depend on the bincode encoding and are easy to reproduce from any
+//! language: a commitment is the SHA256 digest of the secret followed by the random number.
+//!
+//! The library can be built as a WebAssembly module, then bound to JavaScript using
+//! `wasm-bindgen`, and its tests can be run in Node.js:
+//! ```sh
+//! cargo rustc --lib --release --target wasm32-unknown-unknown --features wasm --crate-type cdylib
+//! wasm-bindgen --target web --out-dir pkg target/wasm32-unknown-unknown/release/hashcom_rs.wasm
+//! wasm-pack test --node --features wasm
+//! ```
+//!
+//! These bindings are only available when the `wasm` feature is enabled.
+
+use crate::{Commitment, SHA256BytesCommitment};
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use wasm_bindgen::prelude::*;
+
+/// Forges the commitment to the secret using the random number r.
+///
+/// Throws an error if the random number is too short to hide the secret.
+#[wasm_bindgen]
+pub fn commit(secret: &[u8], r: &[u8]) -> Result<Vec<u8>, JsError> {
+    SHA256BytesCommitment::new(secret, r)
+        .commit()
+        .map(Vec::from)
+        .map_err(|e| JsError::new(&e.to_string()))
+}
+
+/// Verifies that the secret and the random number r open the commitment.
+///
+/// A malformed commitment never holds.
+#[wasm_bindgen]
+pub fn verify(com: &[u8], secret: &[u8], r: &[u8]) -> bool {
+    SHA256BytesCommitment::new(secret, r)
+        .verify(&Commitment::from(com), secret, r)
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{commit, verify};
+    #[cfg(target_arch = "wasm32")]
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    #[cfg_attr(target_arch = "wasm32", wasm_bindgen_test)]
+    #[cfg_attr(not(target_arch = "wasm32"), test)]
+    fn it_commits_and_verifies_raw_bytes() {
+        let s = b"4242";
+        let r = b"2424242424242424";
+
+        let commit = commit(s, r).unwrap();
+
+        assert!(verify(&commit, s, r));
+        assert!(!verify(&commit, b"BDBD", r));
+        assert!(!verify(&commit[..16], s, r))
+    }
+}
@@ -0,0 +1,57 @@
+//! Runs a sealed-bid auction between several bidders, using only the public API of the crate
+//! and the rules of the auction example.
+
+#[path = "../examples/auction/winner.rs"]
+mod winner;
+
+use hashcom_rs::{Commitment, Opening, Result, SHA256Commitment};
+use winner::find_winner;
+
+fn openings(bids: &[u64]) -> Vec<Opening<u64>> {
+    bids.iter()
+        .enumerate()
+        .map(|(index, bid)| Opening::new(*bid, vec![index as u8 + 1; 16]))
+        .collect()
+}
+
+fn commit(openings: &[Opening<u64>]) -> Result<Vec<Commitment>> {
+    openings
+        .iter()
+        .enumerate()
+        .map(|(index, opening)| {
+            SHA256Commitment::commit_indexed(index as u64, opening.secret(), opening.randomness())
+        })
+        .collect()
+}
+
+#[test]
+fn it_finds_the_highest_bidder() {
+    let openings = openings(&[4242, 2424, 4243, 4243]);
+    let commitments = commit(&openings).unwrap();
+
+    assert_eq!(find_winner(&commitments, &openings).unwrap(), Some(2))
+}
+
+#[test]
+fn it_disqualifies_a_changed_bid() {
+    let mut openings = openings(&[4242, 2424, 4200]);
+    let commitments = commit(&openings).unwrap();
+
+    // The second bidder tries to raise its bid once the others have been revealed.
+    openings[1] = Opening::new(5000, openings[1].randomness().to_vec());
+
+    assert_eq!(find_winner(&commitments, &openings).unwrap(), Some(0))
+}
+
+#[test]
+fn it_disqualifies_a_copied_commitment() {
+    let mut openings = openings(&[2424, 4242]);
+    let mut commitments = commit(&openings).unwrap();
+
+    // The first bidder copies the commitment of the second one, then its revealed opening, to
+    // win the tie.
+    commitments[0] = commitments[1].clone();
+    openings[0] = openings[1].clone();
+
+    assert_eq!(find_winner(&commitments, &openings).unwrap(), Some(1))
+}